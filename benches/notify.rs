@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use dioxus::core::NoOpMutations;
+use dioxus::prelude::*;
+use dioxus_radio::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    rows: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Rows,
+    Row(usize),
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+thread_local! {
+    static RADIO: RefCell<Option<Radio<Data, DataChannel>>> = const { RefCell::new(None) };
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct AppProps {
+    rows: usize,
+}
+
+#[allow(non_snake_case)]
+fn App(props: AppProps) -> Element {
+    use_init_radio_station::<Data, DataChannel>(|| Data { rows: props.rows });
+    let radio = use_radio::<Data, DataChannel>(DataChannel::Rows);
+    RADIO.with(|cell| *cell.borrow_mut() = Some(radio));
+
+    let rows = radio.read().rows;
+
+    rsx!(for row in 0..rows {
+        Row { key: "{row}", row }
+    })
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn Row(row: usize) -> Element {
+    let radio = use_radio::<Data, DataChannel>(DataChannel::Row(row));
+    let _ = radio.read();
+    Ok(VNode::placeholder())
+}
+
+/// Mount one component per channel and return the dom along with a radio to write with.
+fn mount(rows: usize) -> (VirtualDom, Radio<Data, DataChannel>) {
+    let mut dom = VirtualDom::new_with_props(App, AppProps { rows });
+    dom.rebuild_in_place();
+    let radio = RADIO.with(|cell| cell.borrow().unwrap());
+    (dom, radio)
+}
+
+fn notify_one_channel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("notify_one_channel");
+    for rows in [100, 1_000, 10_000, 50_000] {
+        let (mut dom, mut radio) = mount(rows);
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &rows, |b, rows| {
+            let mut row = 0;
+            b.iter(|| {
+                dom.in_runtime(|| {
+                    radio.write_channel(DataChannel::Row(row)).rows = *rows;
+                });
+                dom.render_immediate(&mut NoOpMutations);
+                row = (row + 1) % rows;
+            })
+        });
+    }
+    group.finish();
+}
+
+fn notify_unsubscribed_channel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("notify_unsubscribed_channel");
+    for rows in [100, 1_000, 10_000, 50_000] {
+        let (dom, mut radio) = mount(rows);
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &rows, |b, rows| {
+            b.iter(|| {
+                dom.in_runtime(|| {
+                    radio.write_channel(DataChannel::Row(usize::MAX)).rows = *rows;
+                });
+            })
+        });
+    }
+    group.finish();
+}
+
+fn unmount_channels(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unmount_channels");
+    group.sample_size(10);
+    for rows in [1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &rows, |b, rows| {
+            b.iter_batched(
+                || mount(*rows),
+                |(mut dom, mut radio)| {
+                    dom.in_runtime(|| {
+                        radio.write().rows = 0;
+                    });
+                    dom.render_immediate(&mut NoOpMutations);
+                    // The next write cleans up the channels of the unmounted rows
+                    dom.in_runtime(|| {
+                        radio.write().rows = 0;
+                    });
+                },
+                criterion::BatchSize::PerIteration,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    notify_one_channel,
+    notify_unsubscribed_channel,
+    unmount_channels
+);
+criterion_main!(benches);
@@ -0,0 +1,242 @@
+//! Derive macros for [dioxus-radio](https://github.com/dioxus-community/dioxus-radio).
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Fields whose `write_{field}` method would clash with a method of `Radio`.
+const RESERVED_FIELDS: &[&str] = &[
+    "channel",
+    "channel_with",
+    "silently",
+    "with",
+    "with_channel_selection",
+];
+
+/// Generate a channel enum for a state struct, with one variant per field plus `All`.
+///
+/// For a struct named `Data` this generates:
+/// - `DataChannel`, implementing `RadioChannel<Data>`. Notifying any field variant also notifies `All`.
+/// - `DataRadioExt`, implemented for `Radio<Data, DataChannel>`, with a `read_{field}` and `write_{field}` method per field.
+///   Writing a field notifies its own channel.
+///
+/// Fields named `all`, or whose `write_{field}` method would clash with a method of `Radio`, like `channel`, are rejected.
+///
+/// Example:
+///
+/// ```rs
+/// #[derive(Default, RadioChannels)]
+/// struct Data {
+///     pub lists: Vec<Vec<String>>,
+///     pub count: usize,
+/// }
+///
+/// let mut radio = use_radio::<Data, DataChannel>(DataChannel::Count);
+///
+/// *radio.write_count() += 1;
+/// ```
+#[proc_macro_derive(RadioChannels)]
+pub fn derive_radio_channels(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let DeriveInput {
+        ident,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "RadioChannels can not be derived for generic structs",
+        ));
+    }
+
+    let fields = match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "RadioChannels can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "RadioChannels can only be derived for structs",
+            ))
+        }
+    };
+
+    let channel_ident = format_ident!("{ident}Channel");
+    let ext_ident = format_ident!("{ident}RadioExt");
+
+    let mut variants = Vec::new();
+    let mut signatures = Vec::new();
+    let mut methods = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.expect("Named fields always have an ident");
+        let field_ty = field.ty;
+        let field_name = field_ident.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+
+        let variant = format_ident!("{}", to_upper_camel_case(field_name));
+        if variant == "All" {
+            return Err(syn::Error::new_spanned(
+                field_ident,
+                "The `All` channel is reserved, rename this field",
+            ));
+        }
+        if RESERVED_FIELDS.contains(&field_name) {
+            return Err(syn::Error::new_spanned(
+                field_ident,
+                format!("`write_{field_name}` is already a method of `Radio`, rename this field"),
+            ));
+        }
+        let read_fn = format_ident!("read_{field_name}");
+        let write_fn = format_ident!("write_{field_name}");
+        let variant_doc = format!("Changes to `{field_name}`.");
+        let read_doc = format!("Read the `{field_name}` field.");
+        let write_doc =
+            format!("Modify the `{field_name}` field, notifying [`{channel_ident}::{variant}`].");
+
+        let signature_read = quote! {
+            fn #read_fn(&self) -> ::dioxus::prelude::ReadableRef<'static, ::dioxus::prelude::Signal<#field_ty>>
+        };
+        let signature_write = quote! {
+            fn #write_fn(&mut self) -> ::dioxus_radio::prelude::MappedRadioGuard<#field_ty>
+        };
+
+        variants.push(quote! {
+            #[doc = #variant_doc]
+            #variant
+        });
+        signatures.push(quote! {
+            #[doc = #read_doc]
+            #signature_read;
+            #[doc = #write_doc]
+            #signature_write;
+        });
+        methods.push(quote! {
+            #signature_read {
+                self.map((), |value| &value.#field_ident, |value| &mut value.#field_ident)
+                    .read()
+            }
+
+            #signature_write {
+                self.map((), |value| &value.#field_ident, |value| &mut value.#field_ident)
+                    .write_channel(#channel_ident::#variant)
+            }
+        });
+    }
+
+    let channel_doc = format!("Channels of [`{ident}`], one per field.");
+    let ext_doc = format!("Typed accessors to the fields of [`{ident}`].");
+
+    Ok(quote! {
+        #[doc = #channel_doc]
+        #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, PartialOrd, Ord)]
+        #vis enum #channel_ident {
+            #(#variants,)*
+            /// Changes to any field.
+            All,
+        }
+
+        impl ::dioxus_radio::prelude::RadioChannel<#ident> for #channel_ident {
+            fn derive_channel(self, _radio: &#ident) -> Vec<Self> {
+                match self {
+                    Self::All => vec![self],
+                    _ => vec![self, Self::All],
+                }
+            }
+        }
+
+        #[doc = #ext_doc]
+        #vis trait #ext_ident {
+            #(#signatures)*
+        }
+
+        impl #ext_ident for ::dioxus_radio::prelude::Radio<#ident, #channel_ident> {
+            #(#methods)*
+        }
+    })
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn all_is_reserved() {
+        let err = error(parse_quote! {
+            struct Data {
+                all: usize,
+            }
+        });
+        assert_eq!(err, "The `All` channel is reserved, rename this field");
+    }
+
+    #[test]
+    fn radio_methods_are_reserved() {
+        let err = error(parse_quote! {
+            struct Data {
+                channel: usize,
+            }
+        });
+        assert_eq!(
+            err,
+            "`write_channel` is already a method of `Radio`, rename this field"
+        );
+
+        let err = error(parse_quote! {
+            struct Data {
+                r#with: usize,
+            }
+        });
+        assert_eq!(
+            err,
+            "`write_with` is already a method of `Radio`, rename this field"
+        );
+    }
+
+    #[test]
+    fn other_fields_are_accepted() {
+        let tokens = expand(parse_quote! {
+            struct Data {
+                channels: usize,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("write_channels"));
+    }
+}
@@ -0,0 +1,203 @@
+//! Components to inspect a [RadioStation](crate::hooks::RadioStation) while the app runs.
+//!
+//! Enabled with the `devtools` feature.
+
+use std::{collections::VecDeque, fmt::Debug, time::Duration};
+
+use dioxus::prelude::*;
+use web_time::Instant;
+
+use crate::hooks::{use_radio_notifications, use_radio_station, RadioChannel};
+
+/// Window used to measure how often each channel is notified.
+const RATE_WINDOW: Duration = Duration::from_secs(10);
+/// How many notifications are kept to measure the rates.
+const TRACKED_NOTIFICATIONS: usize = 1_000;
+/// How many of the latest notifications are listed.
+const LISTED_NOTIFICATIONS: usize = 20;
+
+struct Notification<Channel> {
+    channel: Channel,
+    at: Instant,
+}
+
+/// Shows the live channels of the [RadioStation](crate::hooks::RadioStation) in the context,
+/// their subscribers, how often they are notified, how many reruns they caused and the latest notifications.
+/// It enables the [metrics](crate::hooks::RadioStation::enable_metrics) of the station while mounted.
+///
+/// Example:
+///
+/// ```rs
+/// rsx!(
+///     RadioInspector::<Data, DataChannel> {}
+/// )
+/// ```
+#[component]
+pub fn RadioInspector<Value: 'static, Channel: RadioChannel<Value> + Debug>() -> Element {
+    let station = use_radio_station::<Value, Channel>();
+    let mounted = use_hook(Instant::now);
+    let mut notifications = use_signal(VecDeque::<Notification<Channel>>::new);
+
+    let metrics_enabled = use_hook(|| {
+        let enabled = station.metrics_enabled();
+        station.enable_metrics();
+        enabled
+    });
+    use_drop(move || {
+        // Leave the metrics as they were, unless the station was dropped already
+        if !metrics_enabled && station.metrics.try_peek_unchecked().is_ok() {
+            station.disable_metrics();
+        }
+    });
+
+    use_radio_notifications::<Value, Channel>(move |channel| {
+        let mut notifications = notifications.write();
+        if notifications.len() == TRACKED_NOTIFICATIONS {
+            notifications.pop_back();
+        }
+        notifications.push_front(Notification {
+            channel: channel.clone(),
+            at: Instant::now(),
+        });
+    });
+
+    let now = Instant::now();
+    let notifications = notifications.read();
+    let metrics = station.metrics();
+    let mut channels = station.inspect().channels;
+    channels.sort_by_cached_key(|snapshot| format!("{:?}", snapshot.channel));
+
+    rsx!(
+        div {
+            style: "font-family: monospace; font-size: 12px;",
+            h4 { "Channels" }
+            table {
+                tr {
+                    th { "Channel" }
+                    th { "Subscribers" }
+                    th { "Scopes" }
+                    th { "Notifications" }
+                    th { "Reruns" }
+                    th { "Rate" }
+                }
+                for snapshot in channels {
+                    {
+                        let recent = notifications
+                            .iter()
+                            .take_while(|notification| now.duration_since(notification.at) < RATE_WINDOW)
+                            .filter(|notification| notification.channel == snapshot.channel)
+                            .count();
+                        let rate = recent as f64 / RATE_WINDOW.as_secs_f64();
+                        let reruns = metrics.channel(&snapshot.channel).map(|metrics| metrics.dirty_marks).unwrap_or_default();
+                        let scopes = snapshot.scopes.iter().map(|scope| scope.0.to_string()).collect::<Vec<_>>().join(", ");
+                        rsx!(
+                            tr {
+                                td { "{snapshot.channel:?}" }
+                                td { "{snapshot.subscribers}" }
+                                td { "{scopes}" }
+                                td { "{snapshot.notifications}" }
+                                td { "{reruns}" }
+                                td { "{rate:.1}/s" }
+                            }
+                        )
+                    }
+                }
+            }
+            h4 { "Latest notifications" }
+            ul {
+                for notification in notifications.iter().take(LISTED_NOTIFICATIONS) {
+                    {
+                        let seconds = notification.at.duration_since(mounted).as_secs_f64();
+                        rsx!(
+                            li { "+{seconds:.1}s {notification.channel:?}" }
+                        )
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Like [RadioInspector] but also showing the current state.
+///
+/// Example:
+///
+/// ```rs
+/// rsx!(
+///     RadioStateInspector::<Data, DataChannel> {}
+/// )
+/// ```
+#[component]
+pub fn RadioStateInspector<Value: Debug + 'static, Channel: RadioChannel<Value> + Debug>() -> Element
+{
+    let station = use_radio_station::<Value, Channel>();
+    let value = station.read();
+
+    rsx!(
+        RadioInspector::<Value, Channel> {}
+        div {
+            style: "font-family: monospace; font-size: 12px;",
+            h4 { "State" }
+            pre { "{value:#?}" }
+        }
+    )
+}
+
+/// Slider over the actions recorded with [`RadioStation::record_actions()`](crate::hooks::RadioStation::record_actions),
+/// showing the state after each of them with [`RadioStation::travel_to()`](crate::hooks::RadioStation::travel_to) until it is resumed.
+///
+/// Enabled along with the `recording` feature.
+///
+/// Example:
+///
+/// ```rs
+/// rsx!(
+///     RadioTimeline::<Data, DataChannel, DataAction> {}
+/// )
+/// ```
+#[cfg(feature = "recording")]
+#[component]
+pub fn RadioTimeline<
+    Value: crate::hooks::DataReducer<Action = Action> + serde::de::DeserializeOwned + Clone + 'static,
+    Channel: RadioChannel<Value> + serde::de::DeserializeOwned,
+    Action: serde::de::DeserializeOwned + 'static,
+>() -> Element {
+    let station = use_radio_station::<Value, Channel>();
+    let mut error = use_signal(|| None::<String>);
+
+    let recorded_actions = station.recorded_actions();
+    let travel_position = station.travel_position();
+    let position = travel_position.unwrap_or(recorded_actions);
+
+    rsx!(
+        div {
+            style: "font-family: monospace; font-size: 12px;",
+            h4 { "Timeline" }
+            input {
+                r#type: "range",
+                min: "0",
+                max: "{recorded_actions}",
+                value: "{position}",
+                oninput: move |event| {
+                    let Ok(index) = event.value().parse::<usize>() else {
+                        return;
+                    };
+                    match station.travel_to::<Action>(index) {
+                        Ok(()) => error.set(None),
+                        Err(err) => error.set(Some(err.to_string())),
+                    }
+                }
+            }
+            span { " {position}/{recorded_actions}" }
+            if travel_position.is_some() {
+                button {
+                    onclick: move |_| station.resume(),
+                    "Resume"
+                }
+            }
+            if let Some(error) = error() {
+                p { "{error}" }
+            }
+        }
+    )
+}
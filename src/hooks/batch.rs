@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::hooks::{Radio, RadioChannel, RadioStation};
+
+/// Ends the batch in progress when dropped, so it also ends if the batch callback panics.
+struct BatchGuard<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    station: RadioStation<Value, Channel>,
+    snapshot: Option<Value>,
+}
+
+impl<Value, Channel> Drop for BatchGuard<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn drop(&mut self) {
+        self.station.finish_batch(self.snapshot.take());
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    pub(crate) fn is_batching(&self) -> bool {
+        self.batch.peek_unchecked().is_some()
+    }
+
+    /// Collect the given channels if there is a batch in progress, returning `false` otherwise.
+    pub(crate) fn defer_channels(&self, channels: &[Channel]) -> bool {
+        match self.batch.write_unchecked().as_mut() {
+            Some(batch) => {
+                batch.extend_from_slice(channels);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Run `cb` collecting the channels of all the writes made inside, and notify them only once at the end.
+    /// Batches inside other batches are merged into the outer one. If `cb` panics, the channels written so far are notified.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.batch(|| {
+    ///     radio.write_channel(DataChannel::ListCreation).lists.push(Vec::default());
+    ///     radio.write_channel(DataChannel::ListCreation).lists.push(Vec::default());
+    /// });
+    /// ```
+    pub fn batch<T>(&self, cb: impl FnOnce() -> T) -> T {
+        if self.is_batching() {
+            return cb();
+        }
+
+        let guard = BatchGuard {
+            station: *self,
+            snapshot: self.snapshot(&self.value.peek_unchecked()),
+        };
+        *self.batch.write_unchecked() = Some(Vec::default());
+
+        let result = cb();
+        drop(guard);
+
+        result
+    }
+
+    /// Notify the channels collected by the batch in progress, once each.
+    fn finish_batch(&self, snapshot: Option<Value>) {
+        // The station might have been dropped already
+        let Ok(mut batch) = self.batch.try_write_unchecked() else {
+            return;
+        };
+        let channels = batch.take().unwrap_or_default();
+        drop(batch);
+
+        let mut seen = HashSet::new();
+        let channels = channels
+            .into_iter()
+            .filter(|channel| seen.insert(channel.clone()))
+            .collect::<Vec<Channel>>();
+
+        // The whole batch is a single step in the history
+        self.record(snapshot, &channels);
+        self.notify_channels(&channels);
+    }
+
+    /// Like [`RadioStation::batch()`], but if `cb` returns an error the state is restored
+    /// to how it was before and the channels written inside are not notified.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.transaction(|| {
+    ///     radio.write_channel(DataChannel::ListCreation).lists.push(Vec::default());
+    ///     validate(&radio.read())
+    /// })?;
+    /// ```
+    pub fn transaction<T, E>(&self, cb: impl FnOnce() -> Result<T, E>) -> Result<T, E>
+    where
+        Value: Clone,
+    {
+        let previous = self.value.peek_unchecked().clone();
+        #[cfg(feature = "recording")]
+        let recorded = self.recording_checkpoint();
+        self.batch(|| {
+            let pending = self.batch.peek_unchecked().as_ref().map_or(0, Vec::len);
+            let result = cb();
+            if result.is_err() {
+                *self.value.write_unchecked() = previous;
+                if let Some(batch) = self.batch.write_unchecked().as_mut() {
+                    batch.truncate(pending);
+                }
+                #[cfg(feature = "recording")]
+                self.rollback_recording(recorded);
+            }
+            result
+        })
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Run `cb` collecting the channels of all the writes made inside, and notify them only once at the end.
+    /// See [`RadioStation::batch()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// radio.batch(|radio| {
+    ///     radio.write_channel(DataChannel::ListCreation).lists.push(Vec::default());
+    ///     radio.write_channel(DataChannel::SpecificListItemUpdate(0)).lists[0].push("Hello".to_string());
+    /// });
+    /// ```
+    pub fn batch<T>(&mut self, cb: impl FnOnce(&mut Self) -> T) -> T {
+        let mut radio = *self;
+        self.station().batch(|| cb(&mut radio))
+    }
+
+    /// Like [`Radio::batch()`], but if `cb` returns an error the state is restored
+    /// to how it was before and the channels written inside are not notified.
+    /// See [`RadioStation::transaction()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// radio.transaction(|radio| {
+    ///     radio.write_channel(DataChannel::ListCreation).lists.push(Vec::default());
+    ///     validate(&radio.read())
+    /// })?;
+    /// ```
+    pub fn transaction<T, E>(&mut self, cb: impl FnOnce(&mut Self) -> Result<T, E>) -> Result<T, E>
+    where
+        Value: Clone,
+    {
+        let mut radio = *self;
+        self.station().transaction(|| cb(&mut radio))
+    }
+}
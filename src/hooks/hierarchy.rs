@@ -0,0 +1,138 @@
+use std::hash::Hash;
+
+use dioxus::prelude::*;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+/// Parent of a channel in a [HierarchicalChannel] tree.
+pub(crate) type ChannelParent<Channel> = fn(&Channel) -> Option<Channel>;
+
+/// A [RadioChannel] organized as a tree, where notifying a channel also notifies all its ancestors.
+///
+/// Example:
+///
+/// ```rs
+/// #[derive(PartialEq, Eq, Clone, Debug, Copy, Hash)]
+/// pub enum DataChannel {
+///     Root,
+///     Items,
+///     Item(usize),
+/// }
+///
+/// impl HierarchicalChannel<Data> for DataChannel {
+///     fn parent(&self) -> Option<Self> {
+///         match self {
+///             Self::Root => None,
+///             Self::Items => Some(Self::Root),
+///             Self::Item(_) => Some(Self::Items),
+///         }
+///     }
+/// }
+///
+/// // Notifies `DataChannel::Item(3)`, `DataChannel::Items` and `DataChannel::Root`
+/// radio.write_channel(DataChannel::Item(3));
+/// ```
+#[cfg(feature = "tracing")]
+pub trait HierarchicalChannel<T>:
+    'static + PartialEq + Eq + Clone + Hash + std::fmt::Debug + Ord
+{
+    /// The channel above this one, or `None` if this is a root channel.
+    fn parent(&self) -> Option<Self>;
+}
+
+#[cfg(not(feature = "tracing"))]
+pub trait HierarchicalChannel<T>: 'static + PartialEq + Eq + Clone + Hash {
+    /// The channel above this one, or `None` if this is a root channel.
+    fn parent(&self) -> Option<Self>;
+}
+
+impl<T, Channel> RadioChannel<T> for Channel
+where
+    Channel: HierarchicalChannel<T>,
+{
+    fn derive_channel(self, _radio: &T) -> Vec<Self> {
+        let mut channels = vec![self];
+        while let Some(parent) = channels.last().and_then(|channel| channel.parent()) {
+            channels.push(parent);
+        }
+        channels
+    }
+}
+
+/// Check if any of the `ancestors` is above `channel` in the hierarchy defined by `parent`.
+pub(crate) fn is_descendant<Channel: PartialEq>(
+    channel: &Channel,
+    ancestors: &[&Channel],
+    parent: ChannelParent<Channel>,
+) -> bool {
+    let mut current = parent(channel);
+    while let Some(channel) = current {
+        if ancestors.contains(&&channel) {
+            return true;
+        }
+        current = parent(&channel);
+    }
+    false
+}
+
+/// The channels that were written to, leaving out the ancestors added by [`HierarchicalChannel`]'s `derive_channel`,
+/// which always come right after the channel they were derived from.
+pub(crate) fn written_channels<Channel: PartialEq>(
+    channels: &[Channel],
+    parent: ChannelParent<Channel>,
+) -> Vec<&Channel> {
+    channels
+        .iter()
+        .enumerate()
+        .filter(|(i, channel)| {
+            i.checked_sub(1)
+                .and_then(|previous| parent(&channels[previous]))
+                .as_ref()
+                != Some(*channel)
+        })
+        .map(|(_, channel)| channel)
+        .collect()
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Add the subscribed descendants of the channels that were written to, but not of their ancestors.
+    pub(crate) fn with_descendants(
+        &self,
+        channels: &[Channel],
+        parent: ChannelParent<Channel>,
+    ) -> Vec<Channel> {
+        let written = written_channels(channels, parent);
+        let descendants = self
+            .listeners
+            .peek_unchecked()
+            .channels()
+            .filter(|listener| {
+                !channels.contains(listener) && is_descendant(*listener, &written, parent)
+            })
+            .cloned()
+            .collect::<Vec<Channel>>();
+        [channels, &descendants].concat()
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: HierarchicalChannel<Value>,
+{
+    /// Also notify all the subscribed descendants of a channel when it is written to,
+    /// e.g. writing to `DataChannel::Items` will wake up `DataChannel::Item(3)`.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let station = use_init_radio_station::<Data, DataChannel>(Data::default);
+    ///
+    /// use_hook(|| station.notify_descendants(true));
+    /// ```
+    pub fn notify_descendants(&self, enabled: bool) {
+        *self.hierarchy.write_unchecked() = if enabled { Some(Channel::parent) } else { None };
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+
+use dioxus::prelude::*;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+struct HistoryEntry<Value, Channel> {
+    value: Value,
+    channels: Vec<Channel>,
+}
+
+/// Undo and redo stacks of a [RadioStation] created with [use_init_radio_station_with_history].
+pub(crate) struct RadioHistory<Value, Channel> {
+    snapshot: fn(&Value) -> Value,
+    depth: usize,
+    undo: VecDeque<HistoryEntry<Value, Channel>>,
+    redo: Vec<HistoryEntry<Value, Channel>>,
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Clone the given value if this station keeps a history.
+    pub(crate) fn snapshot(&self, value: &Value) -> Option<Value> {
+        self.history
+            .peek_unchecked()
+            .as_ref()
+            .map(|history| (history.snapshot)(value))
+    }
+
+    /// Push the value previous to a write, as long as the write notified any channel.
+    pub(crate) fn record(&self, snapshot: Option<Value>, channels: &[Channel]) {
+        let Some(value) = snapshot else {
+            return;
+        };
+        // Batches are recorded as a whole once they finish
+        if channels.is_empty() || self.is_batching() {
+            return;
+        }
+        let mut history = self.history.write_unchecked();
+        let Some(history) = history.as_mut() else {
+            return;
+        };
+        history.redo.clear();
+        history.undo.push_back(HistoryEntry {
+            value,
+            channels: channels.to_vec(),
+        });
+        while history.undo.len() > history.depth {
+            history.undo.pop_front();
+        }
+    }
+
+    fn travel(&self, undo: bool) -> bool {
+        let mut history_ref = self.history.write_unchecked();
+        let Some(history) = history_ref.as_mut() else {
+            return false;
+        };
+        let entry = if undo {
+            history.undo.pop_back()
+        } else {
+            history.redo.pop()
+        };
+        let Some(HistoryEntry { value, channels }) = entry else {
+            return false;
+        };
+
+        let previous = std::mem::replace(&mut *self.value.write_unchecked(), value);
+        let entry = HistoryEntry {
+            value: previous,
+            channels: channels.clone(),
+        };
+        if undo {
+            history.redo.push(entry);
+        } else {
+            history.undo.push_back(entry);
+        }
+        drop(history_ref);
+
+        self.notify_channels(&channels);
+        true
+    }
+
+    /// Revert the last write and notify the channels it touched.
+    /// Returns `false` if there was nothing to undo or this station has no history.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.undo();
+    /// ```
+    pub fn undo(&self) -> bool {
+        self.travel(true)
+    }
+
+    /// Reapply the last undone write and notify the channels it touched.
+    /// Returns `false` if there was nothing to redo or this station has no history.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.redo();
+    /// ```
+    pub fn redo(&self) -> bool {
+        self.travel(false)
+    }
+
+    /// Check if there is any write to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history
+            .peek_unchecked()
+            .as_ref()
+            .is_some_and(|history| !history.undo.is_empty())
+    }
+
+    /// Check if there is any write to redo.
+    pub fn can_redo(&self) -> bool {
+        self.history
+            .peek_unchecked()
+            .as_ref()
+            .is_some_and(|history| !history.redo.is_empty())
+    }
+
+    pub(crate) fn enable_history(&self, depth: usize)
+    where
+        Value: Clone,
+    {
+        *self.history.write_unchecked() = Some(RadioHistory {
+            snapshot: Value::clone,
+            depth,
+            undo: VecDeque::default(),
+            redo: Vec::default(),
+        });
+    }
+
+    /// Forget all the recorded writes.
+    pub fn clear_history(&self) {
+        if let Some(history) = self.history.write_unchecked().as_mut() {
+            history.undo.clear();
+            history.redo.clear();
+        }
+    }
+}
+
+/// Like [use_init_radio_station](crate::hooks::use_init_radio_station) but keeping a snapshot of the state before every write,
+/// so it can be reverted with [`RadioStation::undo()`] and reapplied with [`RadioStation::redo()`].
+/// Only the last `depth` writes are kept.
+///
+/// Example:
+///
+/// ```rs
+/// let station = use_init_radio_station_with_history::<Data, DataChannel>(Data::default, 50);
+/// ```
+pub fn use_init_radio_station_with_history<Value, Channel>(
+    init_value: impl FnOnce() -> Value,
+    depth: usize,
+) -> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: Clone + 'static,
+{
+    use_context_provider(|| {
+        let station = RadioStation::create(init_value());
+        station.enable_history(depth);
+        station
+    })
+}
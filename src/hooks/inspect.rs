@@ -0,0 +1,69 @@
+use dioxus::dioxus_core::ScopeId;
+use dioxus::prelude::*;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+/// Subscribers and activity of a channel, see [`RadioStation::inspect()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChannelSnapshot<Channel> {
+    pub channel: Channel,
+    /// How many reactive contexts are subscribed to the channel.
+    pub subscribers: usize,
+    /// Scopes of the components and hooks subscribed to the channel.
+    pub scopes: Vec<ScopeId>,
+    /// How many times the channel was notified since it was subscribed to.
+    pub notifications: u64,
+}
+
+/// Structured snapshot of the subscriptions of a [RadioStation], see [`RadioStation::inspect()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct StationSnapshot<Channel> {
+    /// Every channel that is subscribed to, in no particular order.
+    pub channels: Vec<ChannelSnapshot<Channel>>,
+    /// How many [use_radio_matching](crate::hooks::use_radio_matching) observers are listening.
+    pub observers: usize,
+}
+
+impl<Channel: PartialEq> StationSnapshot<Channel> {
+    pub fn channel(&self, channel: &Channel) -> Option<&ChannelSnapshot<Channel>> {
+        self.channels
+            .iter()
+            .find(|snapshot| &snapshot.channel == channel)
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Take a snapshot of which channels are subscribed, by whom, and how many times they were notified.
+    /// This doesn't subscribe to anything.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let snapshot = station.inspect();
+    ///
+    /// for channel in snapshot.channels {
+    ///     println!("{:?}: {} subscribers, {} notifications", channel.channel, channel.subscribers, channel.notifications);
+    /// }
+    /// ```
+    pub fn inspect(&self) -> StationSnapshot<Channel> {
+        let channels = self
+            .listeners
+            .peek_unchecked()
+            .iter()
+            .map(|(channel, listeners)| ChannelSnapshot {
+                channel: channel.clone(),
+                subscribers: listeners.len(),
+                scopes: listeners.scopes(),
+                notifications: listeners.counters.lock().unwrap().notified,
+            })
+            .collect();
+
+        StationSnapshot {
+            channels,
+            observers: self.matchers.peek_unchecked().len(),
+        }
+    }
+}
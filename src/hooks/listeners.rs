@@ -0,0 +1,177 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{Arc, Mutex},
+    task::Waker,
+};
+
+use dioxus::dioxus_core::{ReactiveContext, ScopeId, SubscriberList};
+
+use crate::hooks::ChannelCounters;
+
+/// Wakes a [RadioStream](crate::hooks::RadioStream) when its channel is notified.
+#[derive(Default)]
+pub(crate) struct StreamWaker {
+    pub(crate) notified: bool,
+    pub(crate) waker: Option<Waker>,
+}
+
+pub(crate) type SharedStreamWaker = Arc<Mutex<StreamWaker>>;
+
+/// Reactive contexts and streams subscribed to a channel.
+pub(crate) struct ChannelListeners {
+    id: u64,
+    contexts: Mutex<HashSet<ReactiveContext>>,
+    streams: Mutex<Vec<SharedStreamWaker>>,
+    emptied: Arc<Mutex<Vec<u64>>>,
+    /// Activity of the channel, removed along with it
+    pub(crate) counters: Mutex<ChannelCounters>,
+}
+
+impl ChannelListeners {
+    pub(crate) fn contains(&self, reactive_context: &ReactiveContext) -> bool {
+        self.contexts.lock().unwrap().contains(reactive_context)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.contexts.lock().unwrap().len()
+    }
+
+    /// Scopes of the subscribed reactive contexts.
+    pub(crate) fn scopes(&self) -> Vec<ScopeId> {
+        let mut scopes = self
+            .contexts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(ReactiveContext::origin_scope)
+            .collect::<Vec<ScopeId>>();
+        scopes.sort();
+        scopes
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.contexts.lock().unwrap().is_empty() && self.streams.lock().unwrap().is_empty()
+    }
+
+    /// Mark all the reactive contexts as dirty and wake the streams, returning how many reactive contexts there are.
+    pub(crate) fn mark_dirty(&self) -> usize {
+        for stream in self.streams.lock().unwrap().iter() {
+            let mut stream = stream.lock().unwrap();
+            stream.notified = true;
+            if let Some(waker) = stream.waker.take() {
+                waker.wake();
+            }
+        }
+        let contexts = self.contexts.lock().unwrap();
+        for reactive_context in contexts.iter() {
+            reactive_context.mark_dirty();
+        }
+        contexts.len()
+    }
+
+    pub(crate) fn add_stream(&self, stream: SharedStreamWaker) {
+        self.streams.lock().unwrap().push(stream);
+    }
+
+    pub(crate) fn remove_stream(&self, stream: &SharedStreamWaker) {
+        let mut streams = self.streams.lock().unwrap();
+        streams.retain(|other| !Arc::ptr_eq(other, stream));
+
+        // Let the station know this channel might be removed
+        if streams.is_empty() && self.contexts.lock().unwrap().is_empty() {
+            self.emptied.lock().unwrap().push(self.id);
+        }
+    }
+}
+
+impl SubscriberList for ChannelListeners {
+    fn add(&self, subscriber: ReactiveContext) {
+        self.contexts.lock().unwrap().insert(subscriber);
+    }
+
+    fn remove(&self, subscriber: &ReactiveContext) {
+        let mut contexts = self.contexts.lock().unwrap();
+        contexts.remove(subscriber);
+
+        // Let the station know this channel might be removed
+        if contexts.is_empty() && self.streams.lock().unwrap().is_empty() {
+            self.emptied.lock().unwrap().push(self.id);
+        }
+    }
+
+    fn visit(&self, f: &mut dyn FnMut(&ReactiveContext)) {
+        self.contexts.lock().unwrap().iter().for_each(f);
+    }
+}
+
+/// All the subscribers of a [RadioStation](crate::hooks::RadioStation), indexed by channel.
+/// Channels are removed incrementally as their last reactive context is dropped, instead of scanning all of them.
+pub(crate) struct RadioListeners<Channel> {
+    channels: HashMap<Channel, Arc<ChannelListeners>>,
+    ids: HashMap<u64, Channel>,
+    next_id: u64,
+    emptied: Arc<Mutex<Vec<u64>>>,
+}
+
+impl<Channel> Default for RadioListeners<Channel> {
+    fn default() -> Self {
+        Self {
+            channels: HashMap::default(),
+            ids: HashMap::default(),
+            next_id: 0,
+            emptied: Arc::default(),
+        }
+    }
+}
+
+impl<Channel> RadioListeners<Channel>
+where
+    Channel: Eq + Hash + Clone,
+{
+    pub(crate) fn get(&self, channel: &Channel) -> Option<&Arc<ChannelListeners>> {
+        self.channels.get(channel)
+    }
+
+    pub(crate) fn get_or_insert(&mut self, channel: Channel) -> Arc<ChannelListeners> {
+        if let Some(listeners) = self.channels.get(&channel) {
+            return listeners.clone();
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let listeners = Arc::new(ChannelListeners {
+            id,
+            contexts: Mutex::default(),
+            streams: Mutex::default(),
+            emptied: self.emptied.clone(),
+            counters: Mutex::default(),
+        });
+        self.ids.insert(id, channel.clone());
+        self.channels.insert(channel, listeners.clone());
+        listeners
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&Channel, &Arc<ChannelListeners>)> {
+        self.channels.iter()
+    }
+
+    pub(crate) fn channels(&self) -> impl Iterator<Item = &Channel> {
+        self.channels.keys()
+    }
+
+    /// Remove the channels whose reactive contexts were all dropped since the last cleanup.
+    pub(crate) fn cleanup(&mut self) {
+        let emptied = std::mem::take(&mut *self.emptied.lock().unwrap());
+        for id in emptied {
+            let Some(channel) = self.ids.get(&id) else {
+                continue;
+            };
+            // The channel might have been subscribed to again
+            if self.channels[channel].is_empty() {
+                self.channels.remove(channel);
+                self.ids.remove(&id);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    any::Any,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+use dioxus::prelude::*;
+use dioxus::signals::{AnyStorage, UnsyncStorage, WriteLock};
+
+use crate::hooks::{Radio, RadioChannel};
+
+trait AnyMappedRadio<Sub, Channel> {
+    fn read(&self) -> ReadableRef<'static, Signal<Sub>>;
+
+    fn peek(&self) -> ReadableRef<'static, Signal<Sub>>;
+
+    fn write(&self, channel: Option<Channel>) -> MappedRadioGuard<Sub>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    /// Check if `other` maps the same radio with the same key.
+    fn is_same(&self, other: &dyn Any) -> bool;
+}
+
+struct RadioLens<Value, Channel, Key, Read, Write>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    radio: Radio<Value, Channel>,
+    key: Key,
+    read: Read,
+    write: Write,
+}
+
+impl<Value, Sub, Channel, Key, Read, Write> AnyMappedRadio<Sub, Channel>
+    for RadioLens<Value, Channel, Key, Read, Write>
+where
+    Channel: RadioChannel<Value>,
+    Key: PartialEq + 'static,
+    Read: Fn(&Value) -> &Sub + 'static,
+    Write: Fn(&mut Value) -> &mut Sub + 'static,
+    Sub: 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_same(&self, other: &dyn Any) -> bool {
+        other
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.radio == other.radio && self.key == other.key)
+    }
+
+    fn read(&self) -> ReadableRef<'static, Signal<Sub>> {
+        self.radio.subscribe_if_not();
+        self.peek()
+    }
+
+    fn peek(&self) -> ReadableRef<'static, Signal<Sub>> {
+        let value = self.radio.station().value.peek_unchecked();
+        UnsyncStorage::map(value, |value| (self.read)(value))
+    }
+
+    fn write(&self, channel: Option<Channel>) -> MappedRadioGuard<Sub> {
+        let station = self.radio.station();
+        let channel = channel.unwrap_or_else(|| self.radio.channel());
+        let value = station.value.write_unchecked();
+        let channels = channel.derive_channel(&value);
+        let snapshot = station.snapshot(&value);
+        let started = station.write_started();
+        let value = WriteLock::map(value, |value| (self.write)(value))
+            .map_metadata(|metadata| Box::new(metadata) as Box<dyn Any>);
+        MappedRadioGuard {
+            notify: Some(Box::new(move || {
+                station.record_writes(&channels, started);
+                station.record(snapshot, &channels);
+                station.notify_channels(&channels);
+            })),
+            value,
+        }
+    }
+}
+
+/// Like [RadioGuard](crate::hooks::RadioGuard) but only exposing the part of the state selected by a [MappedRadio].
+pub struct MappedRadioGuard<Sub>
+where
+    Sub: 'static,
+{
+    notify: Option<Box<dyn FnOnce()>>,
+    value: WriteLock<'static, Sub, UnsyncStorage, Box<dyn Any>>,
+}
+
+impl<Sub> Drop for MappedRadioGuard<Sub> {
+    fn drop(&mut self) {
+        if let Some(notify) = self.notify.take() {
+            notify();
+        }
+    }
+}
+
+impl<Sub> Deref for MappedRadioGuard<Sub> {
+    type Target = Sub;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<Sub> DerefMut for MappedRadioGuard<Sub> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// `MappedRadio` is a [Radio] scoped to a part of the state, created with [`Radio::map()`].
+/// It reads and writes only the selected part while still notifying through the channels of the original [Radio].
+pub struct MappedRadio<Sub, Channel>
+where
+    Sub: 'static,
+{
+    inner: Rc<dyn AnyMappedRadio<Sub, Channel>>,
+}
+
+impl<Sub, Channel> Clone for MappedRadio<Sub, Channel> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Sub, Channel> PartialEq for MappedRadio<Sub, Channel> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner) || self.inner.is_same(other.inner.as_any())
+    }
+}
+
+impl<Sub, Channel> MappedRadio<Sub, Channel> {
+    /// Read the current sub state value.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let value = mapped_radio.read();
+    /// ```
+    pub fn read(&self) -> ReadableRef<'static, Signal<Sub>> {
+        self.inner.read()
+    }
+
+    /// Read the current sub state value without subscribing.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let value = mapped_radio.peek();
+    /// ```
+    pub fn peek(&self) -> ReadableRef<'static, Signal<Sub>> {
+        self.inner.peek()
+    }
+
+    /// Modify the sub state using the channel of the original [Radio].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// mapped_radio.write().push("Hello, World".to_string());
+    /// ```
+    pub fn write(&mut self) -> MappedRadioGuard<Sub> {
+        self.inner.write(None)
+    }
+
+    /// Modify the sub state using a custom Channel.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// mapped_radio.write_channel(Channel::Whatever).push("Hello, World".to_string());
+    /// ```
+    pub fn write_channel(&mut self, channel: Channel) -> MappedRadioGuard<Sub> {
+        self.inner.write(Some(channel))
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Scope this radio to a part of the state, so it can be passed to components that don't need to know the whole state.
+    /// `key` identifies the part selected by `read` and `write`, e.g. the index of a list. Mapped radios created by the same call
+    /// with equal keys are equal, so memoized components receiving them as props don't rerun when their parent does.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let list = radio.map(
+    ///     list_n,
+    ///     move |data| &data.lists[list_n],
+    ///     move |data| &mut data.lists[list_n],
+    /// );
+    /// ```
+    pub fn map<Sub, Key>(
+        &self,
+        key: Key,
+        read: impl Fn(&Value) -> &Sub + 'static,
+        write: impl Fn(&mut Value) -> &mut Sub + 'static,
+    ) -> MappedRadio<Sub, Channel>
+    where
+        Sub: 'static,
+        Key: PartialEq + 'static,
+    {
+        MappedRadio {
+            inner: Rc::new(RadioLens {
+                radio: *self,
+                key,
+                read,
+                write,
+            }),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use web_time::Instant;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ChannelCounters {
+    /// Always counted, for [`RadioStation::inspect()`].
+    pub(crate) notified: u64,
+    pub(crate) writes: u64,
+    pub(crate) notifications: u64,
+    pub(crate) dirty_marks: u64,
+    pub(crate) write_time: Duration,
+}
+
+/// When the counters of a [RadioStation] were enabled or last reset.
+/// The counters themselves live with the listeners of each channel, so they are removed along with them.
+pub(crate) struct RadioMetrics {
+    since: Instant,
+}
+
+impl Default for RadioMetrics {
+    fn default() -> Self {
+        Self {
+            since: Instant::now(),
+        }
+    }
+}
+
+/// Activity of a channel, see [`RadioStation::metrics()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChannelMetrics<Channel> {
+    pub channel: Channel,
+    /// How many reactive contexts are subscribed to the channel right now.
+    pub subscribers: usize,
+    /// How many write guards notifying this channel were dropped.
+    pub writes: u64,
+    /// How many times the channel was notified.
+    pub notifications: u64,
+    /// How many subscribers were marked dirty by the notifications, each of them reruns.
+    pub dirty_marks: u64,
+    /// Time spent holding the write guards notifying this channel.
+    pub write_time: Duration,
+}
+
+impl<Channel> ChannelMetrics<Channel> {
+    /// Average subscribers marked dirty by each notification.
+    pub fn subscribers_per_notification(&self) -> f64 {
+        if self.notifications == 0 {
+            return 0.0;
+        }
+        self.dirty_marks as f64 / self.notifications as f64
+    }
+}
+
+/// When a channel is considered hot, see [`StationMetrics::hot_channels()`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct HotChannelThreshold {
+    /// Average subscribers marked dirty by each notification.
+    pub subscribers_per_notification: f64,
+    pub notifications_per_second: f64,
+}
+
+impl Default for HotChannelThreshold {
+    fn default() -> Self {
+        Self {
+            subscribers_per_notification: 10.0,
+            notifications_per_second: 10.0,
+        }
+    }
+}
+
+/// Per-channel counters of a [RadioStation], see [`RadioStation::metrics()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct StationMetrics<Channel> {
+    /// Every channel that is subscribed to, in no particular order.
+    pub channels: Vec<ChannelMetrics<Channel>>,
+    /// Time since the station was created or its metrics were reset.
+    pub elapsed: Duration,
+}
+
+impl<Channel: PartialEq> StationMetrics<Channel> {
+    pub fn channel(&self, channel: &Channel) -> Option<&ChannelMetrics<Channel>> {
+        self.channels
+            .iter()
+            .find(|metrics| &metrics.channel == channel)
+    }
+
+    /// How many times per second `metrics` was notified on average.
+    pub fn notifications_per_second(&self, metrics: &ChannelMetrics<Channel>) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        metrics.notifications as f64 / seconds
+    }
+
+    /// Channels that notify too many subscribers too often, causing lots of reruns.
+    /// Sorted by how many subscribers they marked dirty, the hottest first.
+    pub fn hot_channels(&self, threshold: HotChannelThreshold) -> Vec<&ChannelMetrics<Channel>> {
+        let mut hot_channels = self
+            .channels
+            .iter()
+            .filter(|metrics| {
+                metrics.subscribers_per_notification() >= threshold.subscribers_per_notification
+                    && self.notifications_per_second(metrics) >= threshold.notifications_per_second
+            })
+            .collect::<Vec<_>>();
+        hot_channels.sort_by_key(|metrics| std::cmp::Reverse(metrics.dirty_marks));
+        hot_channels
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// When a write started, only measured while the metrics are enabled.
+    pub(crate) fn write_started(&self) -> Option<Instant> {
+        self.metrics
+            .peek_unchecked()
+            .as_ref()
+            .map(|_| Instant::now())
+    }
+
+    pub(crate) fn record_writes(&self, channels: &[Channel], started: Option<Instant>) {
+        let Some(started) = started else {
+            return;
+        };
+        let write_time = started.elapsed();
+        let listeners = self.listeners.peek_unchecked();
+        for channel in channels {
+            if let Some(listeners) = listeners.get(channel) {
+                let mut counters = listeners.counters.lock().unwrap();
+                counters.writes += 1;
+                counters.write_time += write_time;
+            }
+        }
+    }
+
+    /// Start collecting the per-channel counters, they aren't collected by default to keep writes cheap.
+    /// Enabling them again doesn't reset them, see [`RadioStation::reset_metrics()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let station = use_init_radio_station::<Data, DataChannel>(Data::default);
+    ///
+    /// use_hook(|| station.enable_metrics());
+    /// ```
+    pub fn enable_metrics(&self) {
+        if self.metrics.peek_unchecked().is_none() {
+            *self.metrics.write_unchecked() = Some(RadioMetrics::default());
+        }
+    }
+
+    /// Stop collecting the per-channel counters and reset them.
+    /// The notifications shown by [`RadioStation::inspect()`] are still counted.
+    pub fn disable_metrics(&self) {
+        self.reset_counters();
+        *self.metrics.write_unchecked() = None;
+    }
+
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics.peek_unchecked().is_some()
+    }
+
+    /// Get the per-channel counters collected since [`RadioStation::enable_metrics()`] or [`RadioStation::reset_metrics()`] was called.
+    /// Only subscribed channels are counted, and their counters are dropped once they are no longer subscribed to.
+    /// This doesn't subscribe to anything.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let metrics = station.metrics();
+    ///
+    /// for channel in &metrics.channels {
+    ///     println!("{:?}: {} writes, {} reruns", channel.channel, channel.writes, channel.dirty_marks);
+    /// }
+    /// ```
+    pub fn metrics(&self) -> StationMetrics<Channel> {
+        let channels = self
+            .listeners
+            .peek_unchecked()
+            .iter()
+            .map(|(channel, listeners)| {
+                let counters = *listeners.counters.lock().unwrap();
+                ChannelMetrics {
+                    channel: channel.clone(),
+                    subscribers: listeners.len(),
+                    writes: counters.writes,
+                    notifications: counters.notifications,
+                    dirty_marks: counters.dirty_marks,
+                    write_time: counters.write_time,
+                }
+            })
+            .collect();
+
+        StationMetrics {
+            channels,
+            elapsed: self
+                .metrics
+                .peek_unchecked()
+                .as_ref()
+                .map(|metrics| metrics.since.elapsed())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Start counting from zero again, e.g. to only measure a given interaction.
+    pub fn reset_metrics(&self) {
+        self.reset_counters();
+        if let Some(metrics) = self.metrics.write_unchecked().as_mut() {
+            *metrics = RadioMetrics::default();
+        }
+    }
+
+    fn reset_counters(&self) {
+        for (_, listeners) in self.listeners.peek_unchecked().iter() {
+            let mut counters = listeners.counters.lock().unwrap();
+            *counters = ChannelCounters {
+                notified: counters.notified,
+                ..ChannelCounters::default()
+            };
+        }
+    }
+
+    /// Channels that notify too many subscribers too often, see [`StationMetrics::hot_channels()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// for channel in station.hot_channels(HotChannelThreshold::default()) {
+    ///     println!("{channel:?} is causing too many reruns, consider splitting it");
+    /// }
+    /// ```
+    pub fn hot_channels(&self, threshold: HotChannelThreshold) -> Vec<Channel> {
+        self.metrics()
+            .hot_channels(threshold)
+            .into_iter()
+            .map(|metrics| metrics.channel.clone())
+            .collect()
+    }
+
+    /// Export the per-channel counters to the installed [metrics](::metrics) recorder, labeled by channel.
+    /// Counters are exported as absolute values, so they go back to zero after [`RadioStation::reset_metrics()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// // Periodically, e.g. from a spawned task
+    /// station.export_metrics();
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn export_metrics(&self)
+    where
+        Channel: std::fmt::Debug,
+    {
+        for metrics in self.metrics().channels {
+            let channel = format!("{:?}", metrics.channel);
+            ::metrics::counter!("dioxus_radio_writes", "channel" => channel.clone())
+                .absolute(metrics.writes);
+            ::metrics::counter!("dioxus_radio_notifications", "channel" => channel.clone())
+                .absolute(metrics.notifications);
+            ::metrics::counter!("dioxus_radio_dirty_marks", "channel" => channel.clone())
+                .absolute(metrics.dirty_marks);
+            ::metrics::gauge!("dioxus_radio_write_seconds", "channel" => channel.clone())
+                .set(metrics.write_time.as_secs_f64());
+            ::metrics::gauge!("dioxus_radio_subscribers", "channel" => channel)
+                .set(metrics.subscribers as f64);
+        }
+    }
+}
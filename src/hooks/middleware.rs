@@ -0,0 +1,189 @@
+use std::{any::Any, cell::RefCell, ops::ControlFlow, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::hooks::{ChannelSelection, RadioChannel, RadioStation};
+
+/// Hooks into the actions applied with [RadioReducer](crate::hooks::RadioReducer) and [RadioAsyncReducer](crate::hooks::RadioAsyncReducer),
+/// useful for logging, validating, transforming or vetoing actions.
+///
+/// Example:
+///
+/// ```rs
+/// struct Logger;
+///
+/// impl RadioMiddleware<Data, DataChannel, DataAction> for Logger {
+///     fn before(&mut self, action: &mut DataAction, _state: &Data) -> ControlFlow<()> {
+///         println!("Applying {action:?}");
+///         ControlFlow::Continue(())
+///     }
+///
+///     fn after(&mut self, action: &DataAction, _previous: &Data, _next: &Data, _channel_selection: &ChannelSelection<DataChannel>) {
+///         println!("Applied {action:?}");
+///     }
+/// }
+///
+/// station.add_middleware(Logger);
+/// ```
+pub trait RadioMiddleware<Value, Channel, Action> {
+    /// Runs before the action is reduced, with the previous state.
+    /// The action can be modified, or vetoed by returning [ControlFlow::Break], in which case no subscriber will be notified.
+    fn before(&mut self, _action: &mut Action, _state: &Value) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Runs after the action was reduced and the subscribers were notified,
+    /// with the reduced action, the previous and next states and the resulting [ChannelSelection].
+    fn after(
+        &mut self,
+        _action: &Action,
+        _previous: &Value,
+        _next: &Value,
+        _channel_selection: &ChannelSelection<Channel>,
+    ) {
+    }
+}
+
+struct RegisteredMiddleware<Value, Channel, Action> {
+    middleware: RefCell<Box<dyn RadioMiddleware<Value, Channel, Action>>>,
+    clone_action: fn(&Action) -> Action,
+    clone_value: fn(&Value) -> Value,
+    /// Skip the actions applied with [RadioAsyncReducer](crate::hooks::RadioAsyncReducer)
+    synchronous_only: bool,
+}
+
+/// Copy of an action and the state before reducing it, to pass them to [`RadioMiddleware::after()`].
+pub(crate) struct AppliedAction<Value, Action> {
+    action: Action,
+    previous: Value,
+    asynchronous: bool,
+}
+
+/// Middlewares of a [RadioStation], type-erased because each of them is specific to an `Action` type.
+pub(crate) type Middlewares = Vec<Rc<dyn Any>>;
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Register a [RadioMiddleware] that will run for every action of type `Action` applied through this station.
+    /// Middlewares run in the order they were registered.
+    /// The action and the state are cloned for each action, so the hooks can apply other actions or register middlewares.
+    /// A middleware doesn't run for the actions applied from its own hooks.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let station = use_init_radio_station::<Data, DataChannel>(Data::default);
+    ///
+    /// use_hook(|| station.add_middleware(Logger));
+    /// ```
+    pub fn add_middleware<Action>(
+        &self,
+        middleware: impl RadioMiddleware<Value, Channel, Action> + 'static,
+    ) where
+        Value: Clone,
+        Action: Clone + 'static,
+    {
+        self.register_middleware(middleware, false);
+    }
+
+    pub(crate) fn register_middleware<Action>(
+        &self,
+        middleware: impl RadioMiddleware<Value, Channel, Action> + 'static,
+        synchronous_only: bool,
+    ) where
+        Value: Clone,
+        Action: Clone + 'static,
+    {
+        let middleware = RegisteredMiddleware::<Value, Channel, Action> {
+            middleware: RefCell::new(Box::new(middleware)),
+            clone_action: Action::clone,
+            clone_value: Value::clone,
+            synchronous_only,
+        };
+        self.middlewares.write_unchecked().push(Rc::new(middleware));
+    }
+
+    /// Middlewares of the `Action` type, taken out of the station so their hooks can use it.
+    fn middlewares_of<Action>(
+        &self,
+        asynchronous: bool,
+    ) -> Vec<Rc<RegisteredMiddleware<Value, Channel, Action>>>
+    where
+        Action: 'static,
+    {
+        self.middlewares
+            .peek_unchecked()
+            .iter()
+            .filter_map(|middleware| {
+                middleware
+                    .clone()
+                    .downcast::<RegisteredMiddleware<Value, Channel, Action>>()
+                    .ok()
+            })
+            .filter(|registered| !(asynchronous && registered.synchronous_only))
+            .collect()
+    }
+
+    /// Run the `before` hook of all the middlewares of the `Action` type, returning `None` if any of them vetoed the action.
+    /// Otherwise it also returns a copy of the action and the current state if there are any middlewares,
+    /// to be passed to [`RadioStation::run_middlewares_after()`].
+    pub(crate) fn run_middlewares_before<Action>(
+        &self,
+        mut action: Action,
+        asynchronous: bool,
+    ) -> Option<(Action, Option<AppliedAction<Value, Action>>)>
+    where
+        Action: 'static,
+    {
+        let middlewares = self.middlewares_of::<Action>(asynchronous);
+        let Some(first) = middlewares.first() else {
+            return Some((action, None));
+        };
+        let previous = (first.clone_value)(&self.value.peek_unchecked());
+        for registered in &middlewares {
+            let Ok(mut middleware) = registered.middleware.try_borrow_mut() else {
+                continue;
+            };
+            if middleware.before(&mut action, &previous).is_break() {
+                return None;
+            }
+        }
+        let applied_action = AppliedAction {
+            action: (first.clone_action)(&action),
+            previous,
+            asynchronous,
+        };
+        Some((action, Some(applied_action)))
+    }
+
+    /// Run the `after` hook of all the middlewares of the `Action` type.
+    pub(crate) fn run_middlewares_after<Action>(
+        &self,
+        applied_action: Option<AppliedAction<Value, Action>>,
+        channel_selection: &ChannelSelection<Channel>,
+    ) where
+        Action: 'static,
+    {
+        let Some(AppliedAction {
+            action,
+            previous,
+            asynchronous,
+        }) = applied_action
+        else {
+            return;
+        };
+        let middlewares = self.middlewares_of::<Action>(asynchronous);
+        let Some(first) = middlewares.first() else {
+            return;
+        };
+        let next = (first.clone_value)(&self.value.peek_unchecked());
+        for registered in &middlewares {
+            let Ok(mut middleware) = registered.middleware.try_borrow_mut() else {
+                continue;
+            };
+            middleware.after(&action, &previous, &next, channel_selection);
+        }
+    }
+}
@@ -1,3 +1,44 @@
+mod batch;
+mod hierarchy;
+mod history;
+mod inspect;
+mod listeners;
+mod mapped_radio;
+mod metrics;
+mod middleware;
+mod optimistic;
+#[cfg(feature = "persistence")]
+mod persistence;
+#[cfg(feature = "recording")]
+mod recording;
+mod status;
+mod stream;
+mod tasks;
+#[cfg(feature = "recording")]
+mod time_travel;
 mod use_radio;
+mod use_radio_matching;
+mod use_radio_notifications;
+mod use_radio_selector;
 
+pub use hierarchy::*;
+pub use history::*;
+pub use inspect::*;
+pub(crate) use listeners::*;
+pub use mapped_radio::*;
+pub use metrics::*;
+pub use middleware::*;
+pub use optimistic::*;
+#[cfg(feature = "persistence")]
+pub use persistence::*;
+#[cfg(feature = "recording")]
+pub use recording::*;
+pub use status::*;
+pub use stream::*;
+pub use tasks::*;
+#[cfg(feature = "recording")]
+pub use time_travel::*;
 pub use use_radio::*;
+pub use use_radio_matching::*;
+pub use use_radio_notifications::*;
+pub use use_radio_selector::*;
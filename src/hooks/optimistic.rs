@@ -0,0 +1,114 @@
+use dioxus::prelude::*;
+
+use crate::hooks::{Radio, RadioChannel};
+
+type Revert<Value> = Box<dyn FnOnce(&mut Value)>;
+
+/// A provisional change made with [`Radio::optimistic()`], to be either committed or reverted.
+/// It is reverted when dropped without being committed, e.g. when its async action is cancelled.
+pub struct OptimisticUpdate<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    radio: Radio<Value, Channel>,
+    channel: Channel,
+    revert: Option<Revert<Value>>,
+}
+
+impl<Value, Channel> OptimisticUpdate<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Keep the provisional change.
+    pub fn commit(mut self) {
+        self.revert = None;
+    }
+
+    /// Undo the provisional change, keeping any other change made after it.
+    pub fn revert(mut self) {
+        self.rollback();
+    }
+
+    fn rollback(&mut self) {
+        let Some(revert) = self.revert.take() else {
+            return;
+        };
+        // The station might have been dropped already
+        if self.radio.station().value.try_peek_unchecked().is_err() {
+            return;
+        }
+        let mut value = self.radio.write_channel(self.channel.clone());
+        revert(&mut value);
+    }
+}
+
+impl<Value, Channel> Drop for OptimisticUpdate<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn drop(&mut self) {
+        self.rollback();
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Apply a provisional change using the channel this radio was created with, notifying its subscribers right away.
+    /// The returned [OptimisticUpdate] decides whether the change is kept or undone with `revert` once the result is known.
+    /// `revert` runs on the current state, so it must only undo its own change, leaving the changes made since then untouched.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// async fn async_reduce(radio: &mut Radio<Data, DataChannel>, action: DataAction) -> ChannelSelection<DataChannel> {
+    ///     let DataAction::CreateList(list) = action;
+    ///     let id = list.id;
+    ///     let update = radio.optimistic(
+    ///         |data| data.lists.push(list.clone()),
+    ///         move |data| data.lists.retain(|other| other.id != id),
+    ///     );
+    ///     match save_list(list).await {
+    ///         Ok(_) => update.commit(),
+    ///         Err(_) => update.revert(),
+    ///     }
+    ///     ChannelSelection::Silence
+    /// }
+    /// ```
+    pub fn optimistic(
+        &mut self,
+        update: impl FnOnce(&mut Value),
+        revert: impl FnOnce(&mut Value) + 'static,
+    ) -> OptimisticUpdate<Value, Channel> {
+        let channel = self.channel();
+        self.optimistic_channel(channel, update, revert)
+    }
+
+    /// Apply a provisional change using a custom channel, see [`Radio::optimistic()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let update = radio.optimistic_channel(
+    ///     DataChannel::ListCreation,
+    ///     |data| data.lists.push(Vec::default()),
+    ///     |data| drop(data.lists.pop()),
+    /// );
+    /// ```
+    pub fn optimistic_channel(
+        &mut self,
+        channel: Channel,
+        update: impl FnOnce(&mut Value),
+        revert: impl FnOnce(&mut Value) + 'static,
+    ) -> OptimisticUpdate<Value, Channel> {
+        update(&mut self.write_channel(channel.clone()));
+
+        OptimisticUpdate {
+            radio: *self,
+            channel,
+            revert: Some(Box::new(revert)),
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use std::{cell::Cell, fmt, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_core::ReactiveContext;
+use futures_timer::Delay;
+use futures_util::{
+    future::{select, Either},
+    StreamExt,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::hooks::{use_radio_station, RadioChannel};
+
+/// A place where the state of a [RadioStation](crate::hooks::RadioStation) can be saved to and loaded from.
+pub trait RadioStorage {
+    /// Load the previously saved state, if any.
+    fn load(&self) -> Option<String>;
+
+    /// Save the serialized state.
+    fn save(&self, state: &str);
+}
+
+/// [RadioStorage] backed by a file.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RadioStorage for FileStorage {
+    fn load(&self) -> Option<String> {
+        std::fs::read_to_string(&self.path).ok()
+    }
+
+    fn save(&self, state: &str) {
+        #[allow(unused_variables)]
+        if let Err(err) = std::fs::write(&self.path, state) {
+            #[cfg(feature = "tracing")]
+            tracing::error!("Failed to save the state to {:?}: {err}", self.path);
+        }
+    }
+}
+
+/// Upgrade step from a persisted state version to the next one.
+pub type RadioMigration = fn(serde_json::Value) -> serde_json::Value;
+
+/// Error loading a persisted state, see [`RadioPersistence::on_error()`].
+#[derive(Debug)]
+pub enum PersistenceError {
+    /// The snapshot was saved by a newer version, which has more migrations than the registered ones.
+    NewerVersion { version: usize, latest: usize },
+    /// The snapshot or its migrated state couldn't be deserialized.
+    Decode(serde_json::Error),
+}
+
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NewerVersion { version, latest } => write!(
+                f,
+                "the persisted state has version {version} but the latest known version is {latest}"
+            ),
+            Self::Decode(err) => write!(f, "failed to decode the persisted state: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {}
+
+impl From<serde_json::Error> for PersistenceError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Decode(err)
+    }
+}
+
+type ErrorHandler = Box<dyn Fn(&PersistenceError)>;
+
+/// Configuration for [use_radio_persistence].
+pub struct RadioPersistence<Channel> {
+    storage: Rc<dyn RadioStorage>,
+    channels: Vec<Channel>,
+    debounce: Duration,
+    migrations: Vec<RadioMigration>,
+    on_error: Option<ErrorHandler>,
+}
+
+impl<Channel> RadioPersistence<Channel> {
+    /// Persist the state in the given `storage` whenever any of the given `channels` is notified.
+    pub fn new(
+        storage: impl RadioStorage + 'static,
+        channels: impl IntoIterator<Item = Channel>,
+    ) -> Self {
+        Self {
+            storage: Rc::new(storage),
+            channels: channels.into_iter().collect(),
+            debounce: Duration::from_millis(250),
+            migrations: Vec::default(),
+            on_error: None,
+        }
+    }
+
+    /// Wait until no writes happened for the given `debounce` before saving. Defaults to 250ms.
+    /// A save still waiting for the debounce is done right away when the component calling [use_radio_persistence] is dropped,
+    /// but not when the app is closed without unmounting it, e.g. when a browser tab is closed.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Register the next upgrade step of the persisted state.
+    /// The state is saved along with a version number, which is the number of registered migrations,
+    /// so older snapshots are upgraded by applying all the steps registered after their version when loaded.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// RadioPersistence::new(FileStorage::new("state.json"), [DataChannel::ListCreation])
+    ///     // Version 0 -> 1, `lists` used to be called `items`
+    ///     .migration(|mut state| {
+    ///         state["lists"] = state["items"].take();
+    ///         state
+    ///     })
+    /// ```
+    pub fn migration(mut self, migration: RadioMigration) -> Self {
+        self.migrations.push(migration);
+        self
+    }
+
+    /// Called if the saved state can't be loaded.
+    /// The station then keeps its initial value and nothing is saved, so the saved state isn't overwritten.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// RadioPersistence::new(FileStorage::new("state.json"), [DataChannel::ListCreation])
+    ///     .on_error(|err| eprintln!("Couldn't restore the previous session: {err}"))
+    /// ```
+    pub fn on_error(mut self, on_error: impl Fn(&PersistenceError) + 'static) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+}
+
+/// Serialize the state along with the current version.
+fn serialize_state<Value: Serialize>(value: &Value, version: usize) -> Option<String> {
+    let state = serde_json::to_value(value).ok()?;
+    serde_json::to_string(&serde_json::json!({
+        "version": version,
+        "state": state,
+    }))
+    .ok()
+}
+
+/// Deserialize the state applying the migrations it lacks. Snapshots without a version are considered to be version 0.
+fn deserialize_state<Value: DeserializeOwned>(
+    state: &str,
+    migrations: &[RadioMigration],
+) -> Result<Value, PersistenceError> {
+    let snapshot = serde_json::from_str::<serde_json::Value>(state)?;
+    let (version, mut state) = match snapshot {
+        serde_json::Value::Object(mut snapshot)
+            if snapshot.len() == 2
+                && snapshot
+                    .get("version")
+                    .is_some_and(|version| version.is_u64())
+                && snapshot.contains_key("state") =>
+        {
+            let version = snapshot["version"].as_u64().unwrap_or_default() as usize;
+            (version, snapshot.remove("state").unwrap_or_default())
+        }
+        state => (0, state),
+    };
+
+    // Snapshots from newer versions can't be loaded
+    let migrations = migrations
+        .get(version..)
+        .ok_or(PersistenceError::NewerVersion {
+            version,
+            latest: migrations.len(),
+        })?;
+    for migration in migrations {
+        state = migration(state);
+    }
+
+    Ok(serde_json::from_value(state)?)
+}
+
+/// Load the state of the current [RadioStation](crate::hooks::RadioStation) from a [RadioStorage] and save it back after writes on the selected channels.
+/// This should be called right after initializing the station so its children render with the loaded state.
+///
+/// Example:
+///
+/// ```rs
+/// use_init_radio_station::<Data, DataChannel>(Data::default);
+/// use_radio_persistence::<Data, DataChannel>(|| {
+///     RadioPersistence::new(FileStorage::new("state.json"), [DataChannel::ListCreation])
+/// });
+/// ```
+pub fn use_radio_persistence<Value, Channel>(
+    persistence: impl FnOnce() -> RadioPersistence<Channel>,
+) where
+    Channel: RadioChannel<Value>,
+    Value: Serialize + DeserializeOwned + 'static,
+{
+    let station = use_radio_station::<Value, Channel>();
+
+    let flush = use_hook(|| {
+        let RadioPersistence {
+            storage,
+            channels,
+            debounce,
+            migrations,
+            on_error,
+        } = persistence();
+
+        if let Some(state) = storage.load() {
+            match deserialize_state::<Value>(&state, &migrations) {
+                Ok(value) => *station.value.write_unchecked() = value,
+                Err(err) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::error!("Failed to load the persisted state: {err}");
+                    if let Some(on_error) = on_error {
+                        on_error(&err);
+                    }
+                    // Don't overwrite the state that couldn't be loaded
+                    return None;
+                }
+            }
+        }
+
+        let (rc, mut rx) = ReactiveContext::new();
+        for channel in channels {
+            station.listen(channel, rc);
+        }
+
+        let pending = Rc::new(Cell::new(false));
+        let version = migrations.len();
+        let flush: Rc<dyn Fn()> = Rc::new({
+            let pending = pending.clone();
+            move || {
+                if !pending.replace(false) {
+                    return;
+                }
+                // The station might have been dropped already
+                let Ok(value) = station.value.try_peek_unchecked() else {
+                    return;
+                };
+                if let Some(state) = serialize_state(&*value, version) {
+                    storage.save(&state);
+                }
+            }
+        });
+
+        spawn({
+            let flush = flush.clone();
+            async move {
+                while rx.next().await.is_some() {
+                    pending.set(true);
+                    // Wait until there are no more writes for the duration of `debounce`
+                    while !debounce.is_zero() {
+                        match select(rx.next(), Delay::new(debounce)).await {
+                            Either::Left((Some(_), _)) => continue,
+                            _ => break,
+                        }
+                    }
+
+                    flush();
+                }
+            }
+        });
+
+        Some(flush)
+    });
+
+    // Save the writes still waiting for the debounce
+    use_drop(move || {
+        if let Some(flush) = &flush {
+            flush();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Data {
+        lists: Vec<String>,
+    }
+
+    fn rename_items(mut state: serde_json::Value) -> serde_json::Value {
+        state["lists"] = state["items"].take();
+        state
+    }
+
+    fn uppercase(mut state: serde_json::Value) -> serde_json::Value {
+        for list in state["lists"].as_array_mut().unwrap() {
+            *list = json!(list.as_str().unwrap().to_uppercase());
+        }
+        state
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = Data {
+            lists: vec!["a".to_string()],
+        };
+        let state = serialize_state(&data, 2).unwrap();
+        let loaded = deserialize_state::<Data>(&state, &[rename_items, uppercase]).unwrap();
+        assert_eq!(loaded, data);
+    }
+
+    #[test]
+    fn unversioned_snapshots_are_version_zero() {
+        let state = json!({ "lists": ["a"] }).to_string();
+        let loaded = deserialize_state::<Data>(&state, &[]).unwrap();
+        assert_eq!(loaded.lists, ["a"]);
+
+        let state = json!({ "items": ["a"] }).to_string();
+        let loaded = deserialize_state::<Data>(&state, &[rename_items]).unwrap();
+        assert_eq!(loaded.lists, ["a"]);
+    }
+
+    #[test]
+    fn migrations_are_chained_from_the_snapshot_version() {
+        let state = json!({ "version": 0, "state": { "items": ["a"] } }).to_string();
+        let loaded = deserialize_state::<Data>(&state, &[rename_items, uppercase]).unwrap();
+        assert_eq!(loaded.lists, ["A"]);
+
+        let state = json!({ "version": 1, "state": { "lists": ["a"] } }).to_string();
+        let loaded = deserialize_state::<Data>(&state, &[rename_items, uppercase]).unwrap();
+        assert_eq!(loaded.lists, ["A"]);
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let state = json!({ "version": 3, "state": { "lists": [] } }).to_string();
+        let err = deserialize_state::<Data>(&state, &[rename_items]).unwrap_err();
+        assert!(matches!(
+            err,
+            PersistenceError::NewerVersion {
+                version: 3,
+                latest: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn invalid_snapshots_fail_to_decode() {
+        let err = deserialize_state::<Data>("{", &[]).unwrap_err();
+        assert!(matches!(err, PersistenceError::Decode(_)));
+
+        let state = json!({ "version": 0, "state": { "lists": 1 } }).to_string();
+        let err = deserialize_state::<Data>(&state, &[]).unwrap_err();
+        assert!(matches!(err, PersistenceError::Decode(_)));
+    }
+}
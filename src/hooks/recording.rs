@@ -0,0 +1,253 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use dioxus::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use web_time::Instant;
+
+use crate::hooks::{
+    ChannelSelection, DataReducer, RadioChannel, RadioMiddleware, RadioStation, Timeline,
+};
+
+/// An action applied while recording, see [`RadioStation::record_actions()`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordedAction {
+    pub action: serde_json::Value,
+    /// The [ChannelSelection] returned by the reducer, or `None` if it couldn't be serialized.
+    pub channel_selection: Option<serde_json::Value>,
+    /// Milliseconds since the recording started.
+    pub timestamp: u64,
+}
+
+/// The state of a station when the recording started and all the actions applied since then.
+/// It can be saved to a file and replayed later to reproduce a bug.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ActionLog {
+    pub initial_state: serde_json::Value,
+    pub actions: Vec<RecordedAction>,
+}
+
+impl ActionLog {
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Deserialize the initial state and the recorded actions, in order.
+    pub fn decode<Value, Action>(&self) -> Result<(Value, Vec<Action>), serde_json::Error>
+    where
+        Value: DeserializeOwned,
+        Action: DeserializeOwned,
+    {
+        let state = serde_json::from_value(self.initial_state.clone())?;
+        let actions = self
+            .actions
+            .iter()
+            .map(|recorded| serde_json::from_value(recorded.action.clone()))
+            .collect::<Result<_, _>>()?;
+        Ok((state, actions))
+    }
+
+    /// Rebuild the state by reducing all the recorded actions on top of the initial state.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let log = ActionLog::from_json(&std::fs::read_to_string("bug.json")?)?;
+    /// let data = log.replay::<Data, DataAction>()?;
+    /// ```
+    pub fn replay<Value, Action>(&self) -> Result<Value, serde_json::Error>
+    where
+        Value: DataReducer<Action = Action> + DeserializeOwned,
+        Action: DeserializeOwned,
+    {
+        let (mut state, actions) = self.decode::<Value, Action>()?;
+        for action in actions {
+            state.reduce(action);
+        }
+        Ok(state)
+    }
+}
+
+/// Handle to a recording started with [`RadioStation::record_actions()`].
+#[derive(Clone)]
+pub struct ActionRecorder {
+    log: Rc<RefCell<ActionLog>>,
+    recording: Rc<Cell<bool>>,
+}
+
+impl ActionRecorder {
+    /// Everything recorded so far.
+    pub fn log(&self) -> ActionLog {
+        self.log.borrow().clone()
+    }
+
+    /// How many actions have been recorded.
+    pub fn len(&self) -> usize {
+        self.log.borrow().actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.get()
+    }
+
+    /// Stop recording new actions.
+    pub fn stop(&self) {
+        self.recording.set(false);
+    }
+
+    /// Record new actions again after [`ActionRecorder::stop()`].
+    pub fn start(&self) {
+        self.recording.set(true);
+    }
+
+    /// Forget the actions recorded after the first `len` ones.
+    pub(crate) fn truncate(&self, len: usize) {
+        self.log.borrow_mut().actions.truncate(len);
+    }
+}
+
+/// [RadioMiddleware] writing the applied actions to an [ActionLog].
+struct RecorderMiddleware {
+    log: Rc<RefCell<ActionLog>>,
+    recording: Rc<Cell<bool>>,
+    started: Instant,
+    /// Notify the readers of the recording
+    on_record: Box<dyn Fn()>,
+}
+
+impl<Value, Channel, Action> RadioMiddleware<Value, Channel, Action> for RecorderMiddleware
+where
+    Channel: Serialize,
+    Action: Serialize,
+{
+    // Only record the actions once they are reduced, so vetoed ones are left out
+    fn after(
+        &mut self,
+        action: &Action,
+        _previous: &Value,
+        _next: &Value,
+        channel_selection: &ChannelSelection<Channel>,
+    ) {
+        if !self.recording.get() {
+            return;
+        }
+        match serde_json::to_value(action) {
+            Ok(action) => {
+                self.log.borrow_mut().actions.push(RecordedAction {
+                    action,
+                    channel_selection: serde_json::to_value(channel_selection).ok(),
+                    timestamp: self.started.elapsed().as_millis() as u64,
+                });
+                (self.on_record)();
+            }
+            #[allow(unused_variables)]
+            Err(err) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!("Failed to record an action: {err}");
+            }
+        }
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Start recording every action of type `Action` applied through this station, along with the current state.
+    /// The recorder is a [RadioMiddleware] that only records the actions once they are reduced, as the previous middlewares left them.
+    /// Actions applied inside a failed [`RadioStation::transaction()`] are discarded along with their changes.
+    /// Async actions aren't recorded, as they can't be replayed with [`DataReducer::reduce()`].
+    /// The recording can be navigated with [`RadioStation::travel_to()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let station = use_init_radio_station::<Data, DataChannel>(Data::default);
+    ///
+    /// let recorder = use_hook(|| station.record_actions::<DataAction>());
+    ///
+    /// // Later on
+    /// std::fs::write("bug.json", recorder.log().to_json()?)?;
+    /// ```
+    pub fn record_actions<Action>(&self) -> ActionRecorder
+    where
+        Value: Serialize + Clone,
+        Channel: Serialize,
+        Action: Serialize + Clone + 'static,
+    {
+        let initial_state = serde_json::to_value(&*self.value.peek_unchecked()).unwrap_or_default();
+        let recorder = ActionRecorder {
+            log: Rc::new(RefCell::new(ActionLog {
+                initial_state,
+                actions: Vec::default(),
+            })),
+            recording: Rc::new(Cell::new(true)),
+        };
+        let timeline = self.timeline;
+        self.register_middleware::<Action>(
+            RecorderMiddleware {
+                log: recorder.log.clone(),
+                recording: recorder.recording.clone(),
+                started: Instant::now(),
+                on_record: Box::new(move || {
+                    // The station might have been dropped already
+                    let _ = timeline.try_write_unchecked();
+                }),
+            },
+            true,
+        );
+        *self.timeline.write_unchecked() = Some(Timeline::new(recorder.clone()));
+        recorder
+    }
+
+    /// How many actions the current recording has, to discard the ones of a failed transaction with [`RadioStation::rollback_recording()`].
+    pub(crate) fn recording_checkpoint(&self) -> Option<usize> {
+        let timeline = self.timeline.peek_unchecked();
+        Some(timeline.as_ref()?.recorder.len())
+    }
+
+    pub(crate) fn rollback_recording(&self, checkpoint: Option<usize>) {
+        let Some(checkpoint) = checkpoint else {
+            return;
+        };
+        if let Some(timeline) = self.timeline.write_unchecked().as_ref() {
+            timeline.recorder.truncate(checkpoint);
+        }
+    }
+
+    /// Replace the state with the one rebuilt from `log`, notifying all the subscribers.
+    /// See [`ActionLog::replay()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.replay::<DataAction>(&log)?;
+    /// ```
+    pub fn replay<Action>(&self, log: &ActionLog) -> Result<(), serde_json::Error>
+    where
+        Value: DataReducer<Action = Action> + DeserializeOwned,
+        Action: DeserializeOwned,
+    {
+        let state = log.replay::<Value, Action>()?;
+        *self.value.write_unchecked() = state;
+
+        let channels = self
+            .listeners
+            .peek_unchecked()
+            .channels()
+            .cloned()
+            .collect::<Vec<Channel>>();
+        self.notify_channels(&channels);
+        Ok(())
+    }
+}
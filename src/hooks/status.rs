@@ -0,0 +1,230 @@
+use std::{any::Any, collections::HashMap, rc::Rc};
+
+use dioxus::prelude::*;
+use dioxus_core::ReactiveContext;
+
+use crate::hooks::{use_radio_station, Radio, RadioChannel, RadioListeners, RadioStation};
+
+/// Status of the actions applied through a channel, see [use_radio_status].
+#[derive(Clone, Debug)]
+pub enum RadioStatus {
+    /// No action is in flight and the last one didn't fail.
+    Idle,
+    /// Some async actions are in flight.
+    Loading,
+    /// The last action failed, see [RadioStatus::error()].
+    Error(Rc<dyn Any>),
+}
+
+impl PartialEq for RadioStatus {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Idle, Self::Idle) | (Self::Loading, Self::Loading) => true,
+            (Self::Error(a), Self::Error(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl RadioStatus {
+    pub fn is_idle(&self) -> bool {
+        matches!(self, Self::Idle)
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    /// The error of the last failed action, if it is of type `E`.
+    pub fn error<E: 'static>(&self) -> Option<Rc<E>> {
+        match self {
+            Self::Error(error) => error.clone().downcast::<E>().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Pending actions and last error of a channel.
+#[derive(Default)]
+pub(crate) struct ChannelStatus {
+    pending: usize,
+    error: Option<Rc<dyn Any>>,
+}
+
+impl ChannelStatus {
+    fn status(&self) -> RadioStatus {
+        match &self.error {
+            _ if self.pending > 0 => RadioStatus::Loading,
+            Some(error) => RadioStatus::Error(error.clone()),
+            None => RadioStatus::Idle,
+        }
+    }
+}
+
+/// Status of every channel with pending actions or an error, and the reactive contexts reading them.
+pub(crate) struct ChannelStatuses<Channel> {
+    statuses: HashMap<Channel, ChannelStatus>,
+    listeners: RadioListeners<Channel>,
+}
+
+impl<Channel> Default for ChannelStatuses<Channel> {
+    fn default() -> Self {
+        Self {
+            statuses: HashMap::default(),
+            listeners: RadioListeners::default(),
+        }
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Status of the actions applied through `channel`. This only subscribes to the status of `channel`.
+    pub fn status(&self, channel: &Channel) -> RadioStatus {
+        if let Some(rc) = ReactiveContext::current() {
+            let is_listening = self
+                .statuses
+                .peek_unchecked()
+                .listeners
+                .get(channel)
+                .is_some_and(|listeners| listeners.contains(&rc));
+            if !is_listening {
+                let mut statuses = self.statuses.write_unchecked();
+                statuses.listeners.cleanup();
+                rc.subscribe(statuses.listeners.get_or_insert(channel.clone()));
+            }
+        }
+
+        self.statuses
+            .peek_unchecked()
+            .statuses
+            .get(channel)
+            .map(ChannelStatus::status)
+            .unwrap_or(RadioStatus::Idle)
+    }
+
+    /// Modify the status of `channel`, notifying its readers if it changed.
+    fn update_status(&self, channel: &Channel, update: impl FnOnce(&mut ChannelStatus)) {
+        // The station might have been dropped already
+        let Ok(mut statuses) = self.statuses.try_write_unchecked() else {
+            return;
+        };
+        let status = statuses.statuses.entry(channel.clone()).or_default();
+        let previous = status.status();
+        update(status);
+        let changed = status.status() != previous;
+        if status.pending == 0 && status.error.is_none() {
+            statuses.statuses.remove(channel);
+        }
+
+        let listeners = statuses.listeners.get(channel).cloned();
+        drop(statuses);
+        if let Some(listeners) = listeners.filter(|_| changed) {
+            listeners.mark_dirty();
+        }
+    }
+
+    /// Track a new async action applied through `channel`, forgetting its previous error.
+    pub(crate) fn start_loading(&self, channel: &Channel) {
+        self.update_status(channel, |status| {
+            status.pending += 1;
+            status.error = None;
+        });
+    }
+
+    pub(crate) fn stop_loading(&self, channel: &Channel) {
+        self.update_status(channel, |status| {
+            status.pending = status.pending.saturating_sub(1);
+        });
+    }
+
+    pub(crate) fn set_status_error(&self, channel: &Channel, error: Option<Rc<dyn Any>>) {
+        let has_error = self
+            .statuses
+            .peek_unchecked()
+            .statuses
+            .get(channel)
+            .is_some_and(|status| status.error.is_some());
+        // Avoid touching the statuses if nothing changed
+        if error.is_none() && !has_error {
+            return;
+        }
+
+        self.update_status(channel, |status| status.error = error);
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Whether there are async actions in flight that were applied through this radio's channel.
+    /// Only the readers of the status of this channel will rerun when it changes.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// if radio.pending() {
+    ///     // Show a spinner
+    /// }
+    /// ```
+    pub fn pending(&self) -> bool {
+        self.status().is_loading()
+    }
+
+    /// Status of the actions applied through this radio's channel.
+    /// Only the readers of the status of this channel will rerun when it changes.
+    pub fn status(&self) -> RadioStatus {
+        self.station().status(&self.channel())
+    }
+
+    /// Mark the last action applied through this radio's channel as failed with `error`,
+    /// e.g. from [DataAsyncReducer::async_reduce()](crate::hooks::DataAsyncReducer::async_reduce).
+    /// It can be read with [`Radio::last_error()`] and [`use_radio_status`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// async fn async_reduce(radio: &mut Radio<Data, DataChannel>, action: DataAction) -> ChannelSelection<DataChannel> {
+    ///     match fetch_lists().await {
+    ///         Ok(lists) => radio.write().lists = lists,
+    ///         Err(err) => radio.report_error(err),
+    ///     }
+    ///     ChannelSelection::Current
+    /// }
+    /// ```
+    pub fn report_error<E: 'static>(&self, error: E) {
+        let error: Rc<dyn Any> = Rc::new(error);
+        let station = self.station();
+        *station.error.write_unchecked() = Some(error.clone());
+        station.set_status_error(&self.channel(), Some(error));
+    }
+}
+
+/// Subscribe to the status of the actions applied through `channel` without subscribing to its changes,
+/// so only the components showing spinners or errors rerun when an action starts or finishes.
+///
+/// Example:
+///
+/// ```rs
+/// let status = use_radio_status::<Data, DataChannel>(DataChannel::ListCreation);
+///
+/// if status.read().is_loading() {
+///     // Show a spinner
+/// }
+/// ```
+pub fn use_radio_status<Value, Channel>(channel: Channel) -> Memo<RadioStatus>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    let station = use_radio_station::<Value, Channel>();
+    let mut current = use_signal(|| channel.clone());
+
+    if *current.peek() != channel {
+        current.set(channel);
+    }
+
+    use_memo(move || station.status(&current.read()))
+}
@@ -0,0 +1,194 @@
+use std::{
+    fmt,
+    future::Future,
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use dioxus::prelude::*;
+use futures_timer::Delay;
+use futures_util::{
+    future::{select, Either},
+    Stream, StreamExt,
+};
+
+use crate::hooks::{ChannelListeners, Radio, RadioChannel, RadioStation, SharedStreamWaker};
+
+/// Stream of the changes notified to a channel, created with [`RadioStation::subscribe_stream()`].
+/// Changes notified while the previous one hasn't been consumed yet are merged into it.
+pub struct RadioStream {
+    listeners: Arc<ChannelListeners>,
+    waker: SharedStreamWaker,
+}
+
+impl Stream for RadioStream {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        let mut waker = self.waker.lock().unwrap();
+        if std::mem::take(&mut waker.notified) {
+            Poll::Ready(Some(()))
+        } else {
+            waker.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for RadioStream {
+    fn drop(&mut self) {
+        // Let the station know this listener is gone
+        self.listeners.remove_stream(&self.waker);
+    }
+}
+
+/// Error returned by [`RadioStation::wait_for_timeout()`] when the state didn't satisfy the predicate in time.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WaitTimeout;
+
+impl fmt::Display for WaitTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timed out waiting for the radio state")
+    }
+}
+
+impl std::error::Error for WaitTimeout {}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Get a [Stream] that yields every time `channel` is notified,
+    /// so async tasks can react to changes without being a component.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let mut changes = station.subscribe_stream(DataChannel::ListCreation);
+    ///
+    /// spawn(async move {
+    ///     while changes.next().await.is_some() {
+    ///         // Do something
+    ///     }
+    /// });
+    /// ```
+    pub fn subscribe_stream(&self, channel: Channel) -> RadioStream {
+        let listeners = self.listeners.write_unchecked().get_or_insert(channel);
+        let waker = SharedStreamWaker::default();
+        listeners.add_stream(waker.clone());
+        RadioStream { listeners, waker }
+    }
+
+    /// Like [`RadioStation::subscribe_stream()`] but yielding the value returned by `selector` after every change.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let mut lists_len = station.subscribe_stream_with(DataChannel::ListCreation, |data| data.lists.len());
+    ///
+    /// spawn(async move {
+    ///     while let Some(len) = lists_len.next().await {
+    ///         // Do something with `len`
+    ///     }
+    /// });
+    /// ```
+    pub fn subscribe_stream_with<T>(
+        &self,
+        channel: Channel,
+        selector: impl Fn(&Value) -> T,
+    ) -> impl Stream<Item = T> {
+        let station = *self;
+        self.subscribe_stream(channel)
+            .map(move |_| selector(&station.value.peek_unchecked()))
+    }
+
+    /// Wait until a write on `channel` makes `predicate` return `true`, or return right away if it already does.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.wait_for(DataChannel::ListCreation, |data| !data.lists.is_empty()).await;
+    /// ```
+    pub fn wait_for(
+        &self,
+        channel: Channel,
+        predicate: impl Fn(&Value) -> bool + 'static,
+    ) -> impl Future<Output = ()> + 'static {
+        let station = *self;
+        // Subscribe before checking so no write is missed
+        let mut changes = self.subscribe_stream(channel);
+        async move {
+            while !predicate(&station.value.peek_unchecked()) {
+                if changes.next().await.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Like [`RadioStation::wait_for()`] but giving up after `timeout`.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station
+    ///     .wait_for_timeout(DataChannel::ListCreation, Duration::from_secs(5), |data| !data.lists.is_empty())
+    ///     .await?;
+    /// ```
+    pub fn wait_for_timeout(
+        &self,
+        channel: Channel,
+        timeout: Duration,
+        predicate: impl Fn(&Value) -> bool + 'static,
+    ) -> impl Future<Output = Result<(), WaitTimeout>> + 'static {
+        let wait = self.wait_for(channel, predicate);
+        async move {
+            match select(pin!(wait), Delay::new(timeout)).await {
+                Either::Left(_) => Ok(()),
+                Either::Right(_) => Err(WaitTimeout),
+            }
+        }
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Wait until a write on `channel` makes `predicate` return `true`, or return right away if it already does.
+    /// See [`RadioStation::wait_for()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// radio.wait_for(DataChannel::ListCreation, |data| !data.lists.is_empty()).await;
+    /// ```
+    pub fn wait_for(
+        &self,
+        channel: Channel,
+        predicate: impl Fn(&Value) -> bool + 'static,
+    ) -> impl Future<Output = ()> + 'static {
+        self.station().wait_for(channel, predicate)
+    }
+
+    /// Like [`Radio::wait_for()`] but giving up after `timeout`.
+    /// See [`RadioStation::wait_for_timeout()`].
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// radio
+    ///     .wait_for_timeout(DataChannel::ListCreation, Duration::from_secs(5), |data| !data.lists.is_empty())
+    ///     .await?;
+    /// ```
+    pub fn wait_for_timeout(
+        &self,
+        channel: Channel,
+        timeout: Duration,
+        predicate: impl Fn(&Value) -> bool + 'static,
+    ) -> impl Future<Output = Result<(), WaitTimeout>> + 'static {
+        self.station().wait_for_timeout(channel, timeout, predicate)
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    future::{poll_fn, Future},
+    mem::Discriminant,
+    pin::Pin,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use dioxus::prelude::*;
+use dioxus_core::Task;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+/// How an action applied with [RadioAsyncReducer](crate::hooks::RadioAsyncReducer) runs
+/// while other actions of the same kind (enum variant) are still running.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ConcurrencyPolicy {
+    /// Run the action right away, alongside the running ones.
+    #[default]
+    Parallel,
+    /// Run the action once all the running and previously queued ones have finished.
+    Queue,
+    /// Cancel the running and queued actions and run this one instead.
+    TakeLatest,
+    /// Ignore the action if another one is running.
+    DropWhileRunning,
+}
+
+/// Status of a [RadioTask].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TaskStatus {
+    /// Waiting for the previous actions to finish, see [ConcurrencyPolicy::Queue].
+    Queued,
+    Running,
+    Completed,
+    /// Cancelled with [RadioTask::cancel()].
+    Cancelled,
+    /// Cancelled by a newer action, see [ConcurrencyPolicy::TakeLatest].
+    Superseded,
+    /// Never ran, because of [ConcurrencyPolicy::DropWhileRunning] or a vetoing [RadioMiddleware](crate::hooks::RadioMiddleware).
+    Dropped,
+}
+
+impl TaskStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, Self::Queued | Self::Running)
+    }
+}
+
+struct TaskState {
+    status: TaskStatus,
+    task: Option<Task>,
+    wakers: Vec<Waker>,
+    on_finish: Vec<Box<dyn FnOnce()>>,
+}
+
+/// Handle to an action applied with [RadioAsyncReducer](crate::hooks::RadioAsyncReducer).
+#[derive(Clone)]
+pub struct RadioTask {
+    state: Rc<RefCell<TaskState>>,
+}
+
+impl PartialEq for RadioTask {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl RadioTask {
+    pub(crate) fn new(status: TaskStatus) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(TaskState {
+                status,
+                task: None,
+                wakers: Vec::default(),
+                on_finish: Vec::default(),
+            })),
+        }
+    }
+
+    pub fn status(&self) -> TaskStatus {
+        self.state.borrow().status
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.status().is_finished()
+    }
+
+    /// Whether this task was cancelled by a newer action, see [ConcurrencyPolicy::TakeLatest].
+    pub fn is_superseded(&self) -> bool {
+        self.status() == TaskStatus::Superseded
+    }
+
+    /// Stop the task if it hasn't finished yet. Writes already made inside the reducer have notified their subscribers,
+    /// only the channels of the final [ChannelSelection](crate::hooks::ChannelSelection) won't be notified.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let task = radio.async_apply(DataAction::Fetch);
+    ///
+    /// task.cancel();
+    /// ```
+    pub fn cancel(&self) {
+        self.finish(TaskStatus::Cancelled);
+    }
+
+    /// Wait for the task to finish, returning how it did.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let task = radio.async_apply(DataAction::Fetch);
+    ///
+    /// spawn(async move {
+    ///     if task.join().await == TaskStatus::Completed {
+    ///         // Do something
+    ///     }
+    /// });
+    /// ```
+    pub fn join(&self) -> impl Future<Output = TaskStatus> + 'static {
+        let state = self.state.clone();
+        poll_fn(move |cx| {
+            let mut state = state.borrow_mut();
+            if state.status.is_finished() {
+                Poll::Ready(state.status)
+            } else {
+                state.wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    pub(crate) fn finish(&self, status: TaskStatus) {
+        let (task, wakers, on_finish) = {
+            let mut state = self.state.borrow_mut();
+            if state.status.is_finished() {
+                return;
+            }
+            state.status = status;
+            (
+                state.task.take(),
+                std::mem::take(&mut state.wakers),
+                std::mem::take(&mut state.on_finish),
+            )
+        };
+
+        if status != TaskStatus::Completed {
+            if let Some(task) = task {
+                task.cancel();
+            }
+        }
+        for waker in wakers {
+            waker.wake();
+        }
+        for on_finish in on_finish {
+            on_finish();
+        }
+    }
+
+    /// Run `cb` once the task finishes, or right away if it already did.
+    pub(crate) fn on_finish(&self, cb: impl FnOnce() + 'static) {
+        if self.is_finished() {
+            cb();
+        } else {
+            self.state.borrow_mut().on_finish.push(Box::new(cb));
+        }
+    }
+}
+
+type TaskFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Running and queued tasks of an action kind.
+#[derive(Default)]
+struct KindTasks {
+    running: Vec<RadioTask>,
+    queue: VecDeque<(RadioTask, TaskFuture)>,
+}
+
+type ActionTasks<Action> = HashMap<Discriminant<Action>, KindTasks>;
+
+/// Tasks of a [RadioStation], type-erased because each entry is specific to an `Action` type.
+pub(crate) type Tasks = HashMap<TypeId, Box<dyn Any>>;
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn with_kind_tasks<Action, T>(
+        &self,
+        kind: Discriminant<Action>,
+        cb: impl FnOnce(&mut KindTasks) -> T,
+    ) -> Option<T>
+    where
+        Action: 'static,
+    {
+        // The station might have been dropped already
+        let mut tasks = self.tasks.try_write_unchecked().ok()?;
+        let kinds = tasks
+            .entry(TypeId::of::<Action>())
+            .or_insert_with(|| Box::new(ActionTasks::<Action>::default()))
+            .downcast_mut::<ActionTasks<Action>>()?;
+        Some(cb(kinds.entry(kind).or_default()))
+    }
+
+    /// Run `future` following the given [ConcurrencyPolicy] among the tasks of the same `kind`.
+    pub(crate) fn schedule_task<Action>(
+        &self,
+        kind: Discriminant<Action>,
+        policy: ConcurrencyPolicy,
+        future: impl Future<Output = ()> + 'static,
+    ) -> RadioTask
+    where
+        Action: 'static,
+    {
+        let task = RadioTask::new(TaskStatus::Queued);
+        let mut future: Option<TaskFuture> = Some(Box::pin(future));
+
+        let superseded = self.with_kind_tasks(kind, |tasks| match policy {
+            ConcurrencyPolicy::Parallel => Some(Vec::default()),
+            ConcurrencyPolicy::Queue if !tasks.running.is_empty() => {
+                tasks
+                    .queue
+                    .extend(future.take().map(|future| (task.clone(), future)));
+                None
+            }
+            ConcurrencyPolicy::Queue => Some(Vec::default()),
+            ConcurrencyPolicy::TakeLatest => {
+                let mut superseded = std::mem::take(&mut tasks.running);
+                superseded.extend(tasks.queue.drain(..).map(|(task, _)| task));
+                Some(superseded)
+            }
+            ConcurrencyPolicy::DropWhileRunning if !tasks.running.is_empty() => None,
+            ConcurrencyPolicy::DropWhileRunning => Some(Vec::default()),
+        });
+
+        match (superseded.flatten(), future) {
+            (Some(superseded), Some(future)) => {
+                for previous in superseded {
+                    previous.finish(TaskStatus::Superseded);
+                }
+                self.start_task(kind, task.clone(), future);
+            }
+            // Dropped while running, or the station is gone
+            (None, Some(_)) => task.finish(TaskStatus::Dropped),
+            // Queued
+            _ => {}
+        }
+
+        task
+    }
+
+    fn start_task<Action>(&self, kind: Discriminant<Action>, task: RadioTask, future: TaskFuture)
+    where
+        Action: 'static,
+    {
+        let station = *self;
+        self.with_kind_tasks(kind, |tasks| tasks.running.push(task.clone()));
+        task.state.borrow_mut().status = TaskStatus::Running;
+        let finished = task.clone();
+        task.on_finish(move || station.finish_task(kind, &finished));
+
+        let running = task.clone();
+        let handle = spawn(async move {
+            future.await;
+            running.finish(TaskStatus::Completed);
+        });
+        task.state.borrow_mut().task = Some(handle);
+    }
+
+    fn finish_task<Action>(&self, kind: Discriminant<Action>, task: &RadioTask)
+    where
+        Action: 'static,
+    {
+        let next = self.with_kind_tasks(kind, |tasks| {
+            tasks.running.retain(|running| running != task);
+            if !tasks.running.is_empty() {
+                return None;
+            }
+            // Skip the queued tasks that were cancelled in the meantime
+            std::iter::from_fn(|| tasks.queue.pop_front()).find(|(task, _)| !task.is_finished())
+        });
+
+        if let Some((task, future)) = next.flatten() {
+            self.start_task(kind, task, future);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+
+use crate::hooks::{ActionRecorder, ChannelSelection, DataReducer, RadioChannel, RadioStation};
+
+/// Error returned by [`RadioStation::travel_to()`].
+#[derive(Debug)]
+pub enum TimeTravelError {
+    /// The station isn't recording actions, see [`RadioStation::record_actions()`].
+    NotRecording,
+    /// The index is past the last recorded action.
+    OutOfBounds,
+    /// The recorded state or actions couldn't be deserialized.
+    Decode(serde_json::Error),
+}
+
+impl fmt::Display for TimeTravelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotRecording => f.write_str("the station isn't recording actions"),
+            Self::OutOfBounds => f.write_str("there is no recorded action at this index"),
+            Self::Decode(err) => write!(f, "failed to decode the action log: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TimeTravelError {}
+
+impl From<serde_json::Error> for TimeTravelError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Decode(err)
+    }
+}
+
+/// The recording of a [RadioStation] and where it is in it.
+pub(crate) struct Timeline<Value> {
+    pub(crate) recorder: ActionRecorder,
+    /// Index of the state being shown, or `None` if it is the live one.
+    position: Option<usize>,
+    live: Option<Value>,
+    was_recording: bool,
+}
+
+impl<Value> Timeline<Value> {
+    pub(crate) fn new(recorder: ActionRecorder) -> Self {
+        Self {
+            recorder,
+            position: None,
+            live: None,
+            was_recording: false,
+        }
+    }
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn subscribed_channels(&self) -> Vec<Channel> {
+        self.listeners
+            .peek_unchecked()
+            .channels()
+            .cloned()
+            .collect()
+    }
+
+    /// Show the state as it was after the first `index` recorded actions, `0` being the state when the recording started.
+    /// Recording is paused until [`RadioStation::resume()`] brings back the live state,
+    /// so the writes and actions made while travelling aren't recorded and are discarded when resuming.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.travel_to::<DataAction>(3)?;
+    /// ```
+    pub fn travel_to<Action>(&self, index: usize) -> Result<(), TimeTravelError>
+    where
+        Value: DataReducer<Action = Action> + DeserializeOwned + Clone,
+        Channel: DeserializeOwned,
+        Action: DeserializeOwned,
+    {
+        let (log, position) = {
+            let timeline = self.timeline.peek_unchecked();
+            let timeline = timeline.as_ref().ok_or(TimeTravelError::NotRecording)?;
+            (timeline.recorder.log(), timeline.position)
+        };
+        if index > log.actions.len() {
+            return Err(TimeTravelError::OutOfBounds);
+        }
+
+        let (mut state, actions) = log.decode::<Value, Action>()?;
+        for action in actions.into_iter().take(index) {
+            state.reduce(action);
+        }
+
+        // Only the channels of the actions in between changed, unless leaving the live state,
+        // which might have been modified without actions
+        let channels = match position {
+            Some(position) => {
+                let range = position.min(index)..position.max(index);
+                let mut channels = Vec::default();
+                for recorded in &log.actions[range] {
+                    let channel_selection =
+                        recorded.channel_selection.clone().and_then(|selection| {
+                            serde_json::from_value::<ChannelSelection<Channel>>(selection).ok()
+                        });
+                    match channel_selection {
+                        Some(ChannelSelection::Select(channel)) => {
+                            channels.extend(channel.derive_channel(&state))
+                        }
+                        Some(ChannelSelection::Silence) => {}
+                        _ => {
+                            channels = self.subscribed_channels();
+                            break;
+                        }
+                    }
+                }
+                channels
+            }
+            None => self.subscribed_channels(),
+        };
+
+        let mut timeline_ref = self.timeline.write_unchecked();
+        if let Some(timeline) = timeline_ref.as_mut() {
+            if timeline.position.is_none() {
+                timeline.live = Some(self.value.peek_unchecked().clone());
+                timeline.was_recording = timeline.recorder.is_recording();
+                timeline.recorder.stop();
+            }
+            timeline.position = Some(index);
+        }
+        drop(timeline_ref);
+
+        *self.value.write_unchecked() = state;
+        self.notify_channels(&channels);
+        Ok(())
+    }
+
+    /// Go back to the live state after [`RadioStation::travel_to()`], notifying all the subscribers.
+    /// Any change made to the state since travelling is discarded.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// station.resume();
+    /// ```
+    pub fn resume(&self) {
+        let mut timeline_ref = self.timeline.write_unchecked();
+        let Some(timeline) = timeline_ref.as_mut() else {
+            return;
+        };
+        if timeline.position.take().is_none() {
+            return;
+        }
+        if timeline.was_recording {
+            timeline.recorder.start();
+        }
+        let live = timeline.live.take();
+        drop(timeline_ref);
+
+        if let Some(live) = live {
+            *self.value.write_unchecked() = live;
+        }
+        self.notify_channels(&self.subscribed_channels());
+    }
+
+    /// Index of the state shown with [`RadioStation::travel_to()`], or `None` if it is the live one.
+    pub fn travel_position(&self) -> Option<usize> {
+        self.timeline.read().as_ref()?.position
+    }
+
+    /// How many actions have been recorded, see [`RadioStation::record_actions()`].
+    pub fn recorded_actions(&self) -> usize {
+        self.timeline
+            .read()
+            .as_ref()
+            .map(|timeline| timeline.recorder.len())
+            .unwrap_or_default()
+    }
+}
@@ -1,12 +1,19 @@
 use std::{
-    collections::{HashMap, HashSet},
+    any::Any,
     hash::Hash,
     ops::{Deref, DerefMut},
-    sync::{Arc, Mutex},
+    rc::Rc,
 };
 
 use dioxus::prelude::*;
 use dioxus_core::ReactiveContext;
+use web_time::Instant;
+
+use crate::hooks::{
+    ChannelMatcher, ChannelParent, ChannelStatuses, ConcurrencyPolicy, Middlewares,
+    NotificationCallbacks, RadioHistory, RadioListeners, RadioMetrics, RadioTask, TaskStatus,
+    Tasks,
+};
 
 #[cfg(feature = "tracing")]
 pub trait RadioChannel<T>: 'static + PartialEq + Eq + Clone + Hash + std::fmt::Debug + Ord {
@@ -28,8 +35,23 @@ where
     Channel: RadioChannel<Value>,
     Value: 'static,
 {
-    value: Signal<Value>,
-    listeners: Signal<HashMap<Channel, Arc<Mutex<HashSet<ReactiveContext>>>>>,
+    pub(crate) value: Signal<Value>,
+    pub(crate) listeners: Signal<RadioListeners<Channel>>,
+    pub(crate) history: Signal<Option<RadioHistory<Value, Channel>>>,
+    pub(crate) middlewares: Signal<Middlewares>,
+    pub(crate) hierarchy: Signal<Option<ChannelParent<Channel>>>,
+    pub(crate) matchers: Signal<Vec<ChannelMatcher<Channel>>>,
+    pub(crate) batch: Signal<Option<Vec<Channel>>>,
+    pub(crate) error: Signal<Option<Rc<dyn Any>>>,
+    pub(crate) tasks: Signal<Tasks>,
+    pub(crate) statuses: Signal<ChannelStatuses<Channel>>,
+    pub(crate) metrics: Signal<Option<RadioMetrics>>,
+    pub(crate) notification_callbacks: Signal<NotificationCallbacks<Channel>>,
+    #[cfg(feature = "recording")]
+    pub(crate) timeline: Signal<Option<crate::hooks::Timeline<Value>>>,
+    /// Every notified channel, in order, while a [RadioHarness](crate::testing::RadioHarness) is recording them.
+    #[cfg(feature = "test-utils")]
+    pub(crate) notified: Signal<Option<Vec<Channel>>>,
 }
 
 impl<Value, Channel> Clone for RadioStation<Value, Channel>
@@ -47,6 +69,27 @@ impl<Value, Channel> RadioStation<Value, Channel>
 where
     Channel: RadioChannel<Value>,
 {
+    pub(crate) fn create(value: Value) -> Self {
+        RadioStation {
+            value: Signal::new(value),
+            listeners: Signal::default(),
+            history: Signal::default(),
+            middlewares: Signal::default(),
+            hierarchy: Signal::default(),
+            matchers: Signal::default(),
+            batch: Signal::default(),
+            error: Signal::default(),
+            tasks: Signal::default(),
+            statuses: Signal::default(),
+            metrics: Signal::default(),
+            notification_callbacks: Signal::default(),
+            #[cfg(feature = "recording")]
+            timeline: Signal::default(),
+            #[cfg(feature = "test-utils")]
+            notified: Signal::default(),
+        }
+    }
+
     pub(crate) fn is_listening(
         &self,
         channel: &Channel,
@@ -55,28 +98,58 @@ where
         let listeners = self.listeners.peek_unchecked();
         listeners
             .get(channel)
-            .map(|contexts| contexts.lock().unwrap().contains(reactive_context))
+            .map(|listeners| listeners.contains(reactive_context))
             .unwrap_or_default()
     }
 
     pub(crate) fn listen(&self, channel: Channel, reactive_context: ReactiveContext) {
-        let mut listeners = self.listeners.write_unchecked();
-        let listeners = listeners.entry(channel).or_default();
-        reactive_context.subscribe(listeners.clone());
+        let listeners = self.listeners.write_unchecked().get_or_insert(channel);
+        reactive_context.subscribe(listeners);
     }
 
     pub(crate) fn notify_listeners(&self, channel: &Channel) {
-        let listeners = self.listeners.write_unchecked();
-
         #[cfg(feature = "tracing")]
         tracing::info!("Notifying {channel:?}");
 
-        for (listener_channel, listeners) in listeners.iter() {
-            if listener_channel == channel {
-                for reactive_context in listeners.lock().unwrap().iter() {
-                    reactive_context.mark_dirty();
-                }
+        let listeners = self.listeners.peek_unchecked().get(channel).cloned();
+        if let Some(listeners) = listeners {
+            let dirty_marks = listeners.mark_dirty();
+            let mut counters = listeners.counters.lock().unwrap();
+            counters.notified += 1;
+            if self.metrics.peek_unchecked().is_some() {
+                counters.notifications += 1;
+                counters.dirty_marks += dirty_marks as u64;
+            }
+        }
+
+        #[cfg(feature = "test-utils")]
+        if let Some(notified) = self.notified.write_unchecked().as_mut() {
+            notified.push(channel.clone());
+        }
+
+        self.notify_matchers(channel);
+        self.run_notification_callbacks(channel);
+    }
+
+    pub(crate) fn notify_channels(&self, channels: &[Channel]) {
+        // Descendants are resolved before batching, while the written channels can still be told apart from their ancestors
+        let hierarchy = *self.hierarchy.peek_unchecked();
+        let with_descendants;
+        let channels = match hierarchy {
+            Some(parent) => {
+                with_descendants = self.with_descendants(channels, parent);
+                &with_descendants
             }
+            None => channels,
+        };
+        if self.defer_channels(channels) {
+            return;
+        }
+        for channel in channels {
+            self.notify_listeners(channel)
+        }
+        if !channels.is_empty() {
+            self.cleanup();
         }
     }
 
@@ -106,18 +179,18 @@ where
         let mut listeners = self.listeners.write_unchecked();
 
         // Clean up those channels with no reactive contexts
-        listeners.retain(|_, listeners| !listeners.lock().unwrap().is_empty());
+        listeners.cleanup();
 
         #[cfg(feature = "tracing")]
         {
             use itertools::Itertools;
+            use std::collections::HashMap;
             use tracing::{info, span, Level};
 
             let mut channels_subscribers = HashMap::<&Channel, usize>::new();
 
             for (channel, listeners) in listeners.iter() {
-                *channels_subscribers.entry(&channel).or_default() =
-                    listeners.lock().unwrap().len();
+                *channels_subscribers.entry(channel).or_default() = listeners.len();
             }
 
             let span = span!(Level::DEBUG, "Radio Station Metrics");
@@ -136,6 +209,7 @@ where
     Value: 'static,
 {
     pub(crate) channel: Channel,
+    pub(crate) channels: Vec<Channel>,
     station: RadioStation<Value, Channel>,
 }
 
@@ -147,7 +221,23 @@ where
         channel: Channel,
         station: RadioStation<Value, Channel>,
     ) -> RadioAntenna<Value, Channel> {
-        RadioAntenna { channel, station }
+        RadioAntenna {
+            channels: vec![channel.clone()],
+            channel,
+            station,
+        }
+    }
+
+    pub(crate) fn with_channels(
+        channel: Channel,
+        channels: Vec<Channel>,
+        station: RadioStation<Value, Channel>,
+    ) -> RadioAntenna<Value, Channel> {
+        RadioAntenna {
+            channel,
+            channels,
+            station,
+        }
     }
 }
 
@@ -157,7 +247,9 @@ where
     Value: 'static,
 {
     antenna: Signal<RadioAntenna<Value, Channel>>,
-    channels: Vec<Channel>,
+    pub(crate) channels: Vec<Channel>,
+    snapshot: Option<Value>,
+    started: Option<Instant>,
     value: WritableRef<'static, Signal<Value>>,
 }
 
@@ -166,12 +258,10 @@ where
     Channel: RadioChannel<Value>,
 {
     fn drop(&mut self) {
-        for channel in &mut self.channels {
-            self.antenna.peek().station.notify_listeners(channel)
-        }
-        if !self.channels.is_empty() {
-            self.antenna.peek().station.cleanup();
-        }
+        let station = self.antenna.peek().station;
+        station.record_writes(&self.channels, self.started);
+        station.record(self.snapshot.take(), &self.channels);
+        station.notify_channels(&self.channels);
     }
 }
 
@@ -231,15 +321,24 @@ where
         Radio { antenna }
     }
 
+    pub(crate) fn station(&self) -> RadioStation<Value, Channel> {
+        self.antenna.peek().station
+    }
+
+    pub(crate) fn channel(&self) -> Channel {
+        self.antenna.peek().channel.clone()
+    }
+
     pub(crate) fn subscribe_if_not(&self) {
         if let Some(rc) = ReactiveContext::current() {
             let antenna = &self.antenna.write_unchecked();
-            let channel = antenna.channel.clone();
-            let is_listening = antenna.station.is_listening(&channel, &rc);
+            for channel in &antenna.channels {
+                let is_listening = antenna.station.is_listening(channel, &rc);
 
-            // Subscribe the reader reactive context to the channel if it wasn't already
-            if !is_listening {
-                antenna.station.listen(channel, rc);
+                // Subscribe the reader reactive context to the channel if it wasn't already
+                if !is_listening {
+                    antenna.station.listen(channel.clone(), rc);
+                }
             }
         }
     }
@@ -284,7 +383,9 @@ where
         let channel = self.antenna.peek().channel.clone();
         RadioGuard {
             channels: channel.derive_channel(&*value),
+            snapshot: self.antenna.peek().station.snapshot(&value),
             antenna: self.antenna,
+            started: self.antenna.peek().station.write_started(),
             value,
         }
     }
@@ -313,7 +414,9 @@ where
         let value = self.antenna.peek().station.value.write_unchecked();
         RadioGuard {
             channels: channel.derive_channel(&*value),
+            snapshot: self.antenna.peek().station.snapshot(&value),
             antenna: self.antenna,
+            started: self.antenna.peek().station.write_started(),
             value,
         }
     }
@@ -353,11 +456,23 @@ where
     pub fn write_with_channel_selection(
         &mut self,
         cb: impl FnOnce(&mut Value) -> ChannelSelection<Channel>,
+    ) -> ChannelSelection<Channel> {
+        self.write_with_channel_selection_since(None, cb)
+    }
+
+    /// Like [`Radio::write_with_channel_selection()`], but the history keeps `snapshot` instead of the current state,
+    /// for when it was taken before some silent writes.
+    pub(crate) fn write_with_channel_selection_since(
+        &mut self,
+        snapshot: Option<Value>,
+        cb: impl FnOnce(&mut Value) -> ChannelSelection<Channel>,
     ) -> ChannelSelection<Channel> {
         let value = self.antenna.peek().station.value.write_unchecked();
         let mut guard = RadioGuard {
             channels: Vec::default(),
+            snapshot: snapshot.or_else(|| self.antenna.peek().station.snapshot(&value)),
             antenna: self.antenna,
+            started: self.antenna.peek().station.write_started(),
             value,
         };
         let channel_selection = cb(&mut guard.value);
@@ -367,15 +482,49 @@ where
             ChannelSelection::Select(c) => Some(c),
         };
         if let Some(channel) = channel {
-            for channel in channel.derive_channel(&guard.value) {
-                self.antenna.peek().station.notify_listeners(&channel)
-            }
-            self.antenna.peek().station.cleanup();
+            guard.channels = channel.derive_channel(&guard.value);
         }
 
         channel_selection
     }
 
+    /// Like [`Radio::write_with_channel_selection()`] but fallible.
+    /// If the callback returns an error, the state is restored to how it was before the callback and no subscriber is notified.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// radio.try_write_with(|value| {
+    ///     value.count = value.count.checked_add(1).ok_or(Error::Overflow)?;
+    ///     Ok(ChannelSelection::Current)
+    /// })?;
+    /// ```
+    pub fn try_write_with<E>(
+        &mut self,
+        cb: impl FnOnce(&mut Value) -> Result<ChannelSelection<Channel>, E>,
+    ) -> Result<ChannelSelection<Channel>, E>
+    where
+        Value: Clone,
+    {
+        let mut error = None;
+        let channel_selection = self.write_with_channel_selection(|value| {
+            let previous = value.clone();
+            match cb(value) {
+                Ok(channel_selection) => channel_selection,
+                Err(err) => {
+                    *value = previous;
+                    error = Some(err);
+                    ChannelSelection::Silence
+                }
+            }
+        });
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(channel_selection),
+        }
+    }
+
     /// Modify the state silently, no component will be notified.
     ///
     /// This is not recommended, the only intended usage for this is inside [RadioAsyncReducer].
@@ -384,7 +533,9 @@ where
         let value = self.antenna.peek().station.value.write_unchecked();
         RadioGuard {
             channels: Vec::default(),
+            snapshot: None,
             antenna: self.antenna,
+            started: None,
             value,
         }
     }
@@ -393,6 +544,7 @@ where
 impl<Channel> Copy for ChannelSelection<Channel> where Channel: Copy {}
 
 #[derive(Clone)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChannelSelection<Channel> {
     /// Notify the channel associated with the used [Radio].
     Current,
@@ -453,7 +605,49 @@ where
     });
 
     if radio.antenna.peek().channel != channel {
-        radio.antenna.write().channel = channel;
+        let mut antenna = radio.antenna.write();
+        antenna.channels = vec![channel.clone()];
+        antenna.channel = channel;
+    }
+
+    radio
+}
+
+/// Consume the state and subscribe to all the given `channels` at once.
+/// The first channel is the primary one, it will be used by [`Radio::write()`] and [`ChannelSelection::Current`],
+/// while the rest are only used to get notified.
+///
+/// Example:
+///
+/// ```rs
+/// let radio = use_radio_channels::<Data, DataChannel>([
+///     DataChannel::ListCreation,
+///     DataChannel::AnyListItemIsUpdated,
+/// ]);
+/// ```
+pub fn use_radio_channels<Value, Channel>(
+    channels: impl IntoIterator<Item = Channel>,
+) -> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    let station = use_context::<RadioStation<Value, Channel>>();
+    let channels = channels.into_iter().collect::<Vec<Channel>>();
+    let channel = channels
+        .first()
+        .cloned()
+        .expect("use_radio_channels requires at least one channel.");
+
+    let mut radio = use_hook(|| {
+        let antenna = RadioAntenna::with_channels(channel.clone(), channels.clone(), station);
+        Radio::new(Signal::new(antenna))
+    });
+
+    if radio.antenna.peek().channels != channels {
+        let mut antenna = radio.antenna.write();
+        antenna.channels = channels;
+        antenna.channel = channel;
     }
 
     radio
@@ -466,10 +660,7 @@ where
     Channel: RadioChannel<Value>,
     Value: 'static,
 {
-    use_context_provider(|| RadioStation {
-        value: Signal::new(init_value()),
-        listeners: Signal::default(),
-    })
+    use_context_provider(|| RadioStation::create(init_value()))
 }
 
 pub fn use_radio_station<Value, Channel>() -> RadioStation<Value, Channel>
@@ -497,14 +688,106 @@ pub trait RadioReducer {
 impl<
         Data: DataReducer<Channel = Channel, Action = Action>,
         Channel: RadioChannel<Data>,
-        Action,
+        Action: 'static,
     > RadioReducer for Radio<Data, Channel>
 {
     type Action = Action;
     type Channel = Channel;
 
     fn apply(&mut self, action: Action) -> ChannelSelection<Channel> {
-        self.write_with_channel_selection(|data| data.reduce(action))
+        let station = self.station();
+        let Some((action, applied_action)) = station.run_middlewares_before(action, false) else {
+            return ChannelSelection::Silence;
+        };
+        let channel_selection = self.write_with_channel_selection(|data| data.reduce(action));
+        station.run_middlewares_after(applied_action, &channel_selection);
+        channel_selection
+    }
+}
+
+pub trait TryDataReducer {
+    type Channel;
+    type Action;
+    type Error;
+
+    fn try_reduce(
+        &mut self,
+        action: Self::Action,
+    ) -> Result<ChannelSelection<Self::Channel>, Self::Error>;
+}
+
+pub trait RadioTryReducer {
+    type Action;
+    type Channel;
+    type Error;
+
+    fn try_apply(
+        &mut self,
+        action: Self::Action,
+    ) -> Result<ChannelSelection<Self::Channel>, Self::Error>;
+}
+
+impl<
+        Data: TryDataReducer<Channel = Channel, Action = Action, Error = Error> + Clone,
+        Channel: RadioChannel<Data>,
+        Action: 'static,
+        Error: Clone + 'static,
+    > RadioTryReducer for Radio<Data, Channel>
+{
+    type Action = Action;
+    type Channel = Channel;
+    type Error = Error;
+
+    /// Apply the action, or restore the state and store the error so it can be read with [`Radio::last_error()`] if it fails.
+    fn try_apply(&mut self, action: Action) -> Result<ChannelSelection<Channel>, Error> {
+        let station = self.station();
+        let Some((action, applied_action)) = station.run_middlewares_before(action, false) else {
+            return Ok(ChannelSelection::Silence);
+        };
+        match self.try_write_with(|data| data.try_reduce(action)) {
+            Ok(channel_selection) => {
+                if station.error.peek().is_some() {
+                    station.error.write_unchecked().take();
+                }
+                station.set_status_error(&self.channel(), None);
+                station.run_middlewares_after(applied_action, &channel_selection);
+                Ok(channel_selection)
+            }
+            Err(err) => {
+                self.report_error(err.clone());
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<Value, Channel> Radio<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Read the error of the last failed [`RadioTryReducer::try_apply()`], if the last one failed and its error is of type `E`.
+    /// Only the readers of the error will rerun when it changes.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// if let Some(err) = radio.last_error::<DataError>() {
+    ///     // Show `err`
+    /// }
+    /// ```
+    pub fn last_error<E: 'static>(&self) -> Option<Rc<E>> {
+        let error = self.station().error.read().clone()?;
+        error.downcast::<E>().ok()
+    }
+
+    /// Forget the error of the last failed [`RadioTryReducer::try_apply()`].
+    pub fn clear_error(&mut self) {
+        let station = self.station();
+        let mut error = station.error;
+        if error.peek().is_some() {
+            error.set(None);
+        }
+        station.set_status_error(&self.channel(), None);
     }
 }
 
@@ -520,12 +803,20 @@ pub trait DataAsyncReducer {
     where
         Self::Channel: RadioChannel<Self>,
         Self: Sized;
+
+    /// How to run an action while others of the same kind (enum variant) are still running.
+    fn concurrency(_action: &Self::Action) -> ConcurrencyPolicy
+    where
+        Self: Sized,
+    {
+        ConcurrencyPolicy::Parallel
+    }
 }
 
 pub trait RadioAsyncReducer {
     type Action;
 
-    fn async_apply(&mut self, _action: Self::Action)
+    fn async_apply(&mut self, _action: Self::Action) -> RadioTask
     where
         Self::Action: 'static;
 }
@@ -538,14 +829,31 @@ impl<
 {
     type Action = Action;
 
-    fn async_apply(&mut self, action: Self::Action)
+    /// Run the action in a new task following its [ConcurrencyPolicy], see [DataAsyncReducer::concurrency()].
+    fn async_apply(&mut self, action: Self::Action) -> RadioTask
     where
         Self::Action: 'static,
     {
         let mut radio = *self;
-        spawn(async move {
+        let station = self.station();
+        let Some((action, applied_action)) = station.run_middlewares_before(action, true) else {
+            return RadioTask::new(TaskStatus::Dropped);
+        };
+        let policy = Data::concurrency(&action);
+        let kind = std::mem::discriminant(&action);
+        let channel = self.channel();
+        let task = station.schedule_task(kind, policy, async move {
+            // The reducer writes silently, so the history keeps the state from before it started
+            let snapshot = station.snapshot(&station.value.peek_unchecked());
             let channel = Data::async_reduce(&mut radio, action).await;
-            radio.write_with_channel_selection(|_| channel);
+            let channel_selection = radio.write_with_channel_selection_since(snapshot, |_| channel);
+            station.run_middlewares_after(applied_action, &channel_selection);
         });
+        // Actions dropped because of their policy don't affect the status
+        if !task.is_finished() {
+            station.start_loading(&channel);
+            task.on_finish(move || station.stop_loading(&channel));
+        }
+        task
     }
 }
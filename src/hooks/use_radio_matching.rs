@@ -0,0 +1,162 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use dioxus::prelude::*;
+use dioxus_core::ReactiveContext;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+type Listeners = Arc<Mutex<HashSet<ReactiveContext>>>;
+
+/// Subscribers of a [RadioStation] that are interested in any channel matching a predicate.
+pub(crate) struct ChannelMatcher<Channel> {
+    predicate: Box<dyn Fn(&Channel) -> bool>,
+    listeners: Listeners,
+}
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    pub(crate) fn notify_matchers(&self, channel: &Channel) {
+        let matchers = self.matchers.peek_unchecked();
+        for matcher in matchers.iter() {
+            if (matcher.predicate)(channel) {
+                for reactive_context in matcher.listeners.lock().unwrap().iter() {
+                    reactive_context.mark_dirty();
+                }
+            }
+        }
+    }
+}
+
+/// `RadioObserver` lets you read the state and is subscribed to every channel matching a predicate.
+/// Created with [use_radio_matching].
+pub struct RadioObserver<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    station: RadioStation<Value, Channel>,
+    listeners: CopyValue<Listeners>,
+}
+
+impl<Value, Channel> Clone for RadioObserver<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Value, Channel> Copy for RadioObserver<Value, Channel> where Channel: RadioChannel<Value> {}
+
+impl<Value, Channel> PartialEq for RadioObserver<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.listeners == other.listeners
+    }
+}
+
+impl<Value, Channel> RadioObserver<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn subscribe_if_not(&self) {
+        if let Some(rc) = ReactiveContext::current() {
+            let listeners = self.listeners.peek();
+            let is_listening = listeners.lock().unwrap().contains(&rc);
+
+            // Subscribe the reader reactive context to the matcher if it wasn't already
+            if !is_listening {
+                rc.subscribe(listeners.clone());
+            }
+        }
+    }
+
+    /// Read the current state value.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let value = observer.read();
+    /// ```
+    pub fn read(&self) -> ReadableRef<'_, Signal<Value>> {
+        self.subscribe_if_not();
+        self.station.value.peek_unchecked()
+    }
+
+    /// Read the current state value inside a callback.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// observer.with(|value| {
+    ///     // Do something with `value`
+    /// });
+    /// ```
+    pub fn with(&self, cb: impl FnOnce(ReadableRef<Signal<Value>>)) {
+        self.subscribe_if_not();
+        cb(self.station.value.peek_unchecked());
+    }
+}
+
+/// Consume the state and subscribe to every channel for which `predicate` returns `true`.
+/// This is useful to listen to a whole family of parameterized channels without enumerating them.
+///
+/// Example:
+///
+/// ```rs
+/// let observer = use_radio_matching::<Data, DataChannel>(|channel| {
+///     matches!(channel, DataChannel::SpecificListItemUpdate(_))
+/// });
+/// ```
+pub fn use_radio_matching<Value, Channel>(
+    predicate: impl Fn(&Channel) -> bool + 'static,
+) -> RadioObserver<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    let station = use_context::<RadioStation<Value, Channel>>();
+    let mut predicate = Some(predicate);
+
+    let observer = use_hook(|| {
+        let listeners = Listeners::default();
+        station.matchers.write_unchecked().push(ChannelMatcher {
+            predicate: Box::new(predicate.take().unwrap()),
+            listeners: listeners.clone(),
+        });
+        RadioObserver {
+            station,
+            listeners: CopyValue::new(listeners),
+        }
+    });
+
+    // Keep the predicate up to date with the latest captured values
+    if let Some(predicate) = predicate {
+        let listeners = observer.listeners.peek();
+        let mut matchers = station.matchers.write_unchecked();
+        if let Some(matcher) = matchers
+            .iter_mut()
+            .find(|matcher| Arc::ptr_eq(&matcher.listeners, &listeners))
+        {
+            matcher.predicate = Box::new(predicate);
+        }
+    }
+
+    let listeners = observer.listeners.peek().clone();
+    use_drop(move || {
+        // The station might have been dropped already
+        if let Ok(mut matchers) = station.matchers.try_write_unchecked() {
+            matchers.retain(|matcher| !Arc::ptr_eq(&matcher.listeners, &listeners));
+        }
+    });
+
+    observer
+}
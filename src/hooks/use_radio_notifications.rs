@@ -0,0 +1,69 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::hooks::{RadioChannel, RadioStation};
+
+type NotificationCallback<Channel> = Rc<RefCell<Box<dyn FnMut(&Channel)>>>;
+
+/// Callbacks of a [RadioStation] run with every notified channel, registered with [use_radio_notifications].
+pub(crate) type NotificationCallbacks<Channel> = Vec<NotificationCallback<Channel>>;
+
+impl<Value, Channel> RadioStation<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    pub(crate) fn run_notification_callbacks(&self, channel: &Channel) {
+        // Let the callbacks write to the station
+        let callbacks = self.notification_callbacks.peek_unchecked().clone();
+        for callback in callbacks {
+            // A callback doesn't run for the notifications caused by itself
+            if let Ok(mut callback) = callback.try_borrow_mut() {
+                callback(channel);
+            }
+        }
+    }
+}
+
+/// Run `callback` with every channel notified by the station, e.g. to log or count them.
+/// Unlike [use_radio_matching](crate::hooks::use_radio_matching), this doesn't subscribe the component to anything.
+///
+/// Example:
+///
+/// ```rs
+/// use_radio_notifications::<Data, DataChannel>(|channel| {
+///     println!("{channel:?} was notified");
+/// });
+/// ```
+pub fn use_radio_notifications<Value, Channel>(callback: impl FnMut(&Channel) + 'static)
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    let station = use_context::<RadioStation<Value, Channel>>();
+    let mut callback = Some(callback);
+
+    let registered = use_hook(|| {
+        let registered: NotificationCallback<Channel> =
+            Rc::new(RefCell::new(Box::new(callback.take().unwrap())));
+        station
+            .notification_callbacks
+            .write_unchecked()
+            .push(registered.clone());
+        registered
+    });
+
+    // Keep the callback up to date with the latest captured values
+    if let Some(callback) = callback {
+        if let Ok(mut registered) = registered.try_borrow_mut() {
+            *registered = Box::new(callback);
+        }
+    }
+
+    use_drop(move || {
+        // The station might have been dropped already
+        if let Ok(mut callbacks) = station.notification_callbacks.try_write_unchecked() {
+            callbacks.retain(|callback| !Rc::ptr_eq(callback, &registered));
+        }
+    });
+}
@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+
+use crate::hooks::{use_radio, RadioChannel};
+
+/// Subscribe to the given `channel` but only rerun when the value returned by `selector` changes.
+/// This gives value-level precision on top of the channel-level precision of [`use_radio`].
+///
+/// Example:
+///
+/// ```rs
+/// let lists_len = use_radio_selector::<Data, DataChannel, _>(DataChannel::ListCreation, |data| {
+///     data.lists.len()
+/// });
+///
+/// let lists_len = *lists_len.read();
+/// ```
+pub fn use_radio_selector<Value, Channel, T>(
+    channel: Channel,
+    mut selector: impl FnMut(&Value) -> T + 'static,
+) -> Memo<T>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+    T: PartialEq + 'static,
+{
+    let radio = use_radio::<Value, Channel>(channel);
+
+    use_memo(move || selector(&radio.read()))
+}
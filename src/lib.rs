@@ -1,5 +1,14 @@
+#[cfg(feature = "devtools")]
+pub mod devtools;
 pub mod hooks;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 
 pub mod prelude {
+    #[cfg(feature = "devtools")]
+    pub use crate::devtools::*;
     pub use crate::hooks::*;
+
+    #[cfg(feature = "macros")]
+    pub use dioxus_radio_macros::RadioChannels;
 }
@@ -0,0 +1,321 @@
+//! Utilities to test radio stations and reducers without launching a renderer.
+//!
+//! Enabled with the `test-utils` feature.
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use dioxus::dioxus_core::{NoOpMutations, ScopeId, VirtualDom};
+use dioxus::prelude::*;
+
+use crate::hooks::{
+    use_init_radio_station, use_radio, ChannelSelection, DataReducer, Radio, RadioAntenna,
+    RadioChannel, RadioReducer, RadioStation,
+};
+
+/// A component mounted by a [RadioHarness], subscribed to a channel.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Probe(usize);
+
+struct ProbeState<Channel> {
+    channel: Channel,
+    renders: usize,
+    mounted: bool,
+}
+
+/// Configures the station once it's created, e.g. to keep a history.
+type Setup<Value, Channel> = Box<dyn FnOnce(RadioStation<Value, Channel>)>;
+
+struct HarnessState<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    init_value: Option<Box<dyn FnOnce() -> Value>>,
+    setup: Option<Setup<Value, Channel>>,
+    station: Option<RadioStation<Value, Channel>>,
+    probes: Vec<ProbeState<Channel>>,
+    radios: HashMap<Channel, Radio<Value, Channel>>,
+}
+
+type SharedState<Value, Channel> = Rc<RefCell<HarnessState<Value, Channel>>>;
+
+struct HarnessProps<Value, Channel>(SharedState<Value, Channel>)
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static;
+
+impl<Value, Channel> Clone for HarnessProps<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Renders the probe with the given index, type-erased so [ProbeComponent] doesn't need generics.
+#[derive(Clone)]
+struct ProbeRenderer(Rc<dyn Fn(usize)>);
+
+#[component]
+fn ProbeComponent(index: usize) -> Element {
+    let renderer = use_context::<ProbeRenderer>();
+    (renderer.0)(index);
+    rsx!()
+}
+
+fn harness_root<Value, Channel>(props: HarnessProps<Value, Channel>) -> Element
+where
+    Channel: RadioChannel<Value>,
+{
+    let state = props.0;
+
+    let station = use_init_radio_station::<Value, Channel>(|| {
+        let init_value = state.borrow_mut().init_value.take();
+        init_value.expect("The harness station is only created once")()
+    });
+    use_hook(|| {
+        *station.notified.write_unchecked() = Some(Vec::default());
+        let setup = state.borrow_mut().setup.take();
+        if let Some(setup) = setup {
+            setup(station);
+        }
+        state.borrow_mut().station = Some(station);
+    });
+
+    use_context_provider(|| {
+        let state = state.clone();
+        ProbeRenderer(Rc::new(move |index| {
+            let channel = state.borrow().probes[index].channel.clone();
+            let radio = use_radio::<Value, Channel>(channel);
+            // Reading subscribes the probe to its channel
+            drop(radio.read());
+            state.borrow_mut().probes[index].renders += 1;
+        }))
+    });
+
+    let probes = state
+        .borrow()
+        .probes
+        .iter()
+        .enumerate()
+        .filter(|(_, probe)| probe.mounted)
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    rsx!(for index in probes {
+        ProbeComponent {
+            key: "{index}",
+            index,
+        }
+    })
+}
+
+/// A [RadioStation] living inside a headless [VirtualDom], with probe components subscribed to channels
+/// to check which channels get notified by writes and actions, and which subscribers rerun.
+///
+/// Example:
+///
+/// ```rs
+/// let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+/// let probe = harness.probe(DataChannel::ListCreation);
+///
+/// harness.apply(DataChannel::ListCreation, DataAction::AddList);
+///
+/// assert_eq!(harness.notified(&DataChannel::ListCreation), 1);
+/// assert_eq!(harness.reruns(probe), 1);
+/// ```
+pub struct RadioHarness<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+    Value: 'static,
+{
+    dom: VirtualDom,
+    state: SharedState<Value, Channel>,
+}
+
+impl<Value, Channel> RadioHarness<Value, Channel>
+where
+    Channel: RadioChannel<Value>,
+{
+    /// Create the station with `init_value` and render it.
+    pub fn new(init_value: impl FnOnce() -> Value + 'static) -> Self {
+        Self::create(init_value, None)
+    }
+
+    /// Like [`RadioHarness::new()`], but the station keeps a history of the last `depth` writes,
+    /// like [use_init_radio_station_with_history](crate::hooks::use_init_radio_station_with_history).
+    pub fn with_history(init_value: impl FnOnce() -> Value + 'static, depth: usize) -> Self
+    where
+        Value: Clone,
+    {
+        Self::create(
+            init_value,
+            Some(Box::new(move |station| station.enable_history(depth))),
+        )
+    }
+
+    fn create(
+        init_value: impl FnOnce() -> Value + 'static,
+        setup: Option<Setup<Value, Channel>>,
+    ) -> Self {
+        let state = Rc::new(RefCell::new(HarnessState {
+            init_value: Some(Box::new(init_value)),
+            setup,
+            station: None,
+            probes: Vec::default(),
+            radios: HashMap::default(),
+        }));
+        let mut dom =
+            VirtualDom::new_with_props(harness_root::<Value, Channel>, HarnessProps(state.clone()));
+        dom.rebuild_in_place();
+        Self { dom, state }
+    }
+
+    pub fn station(&self) -> RadioStation<Value, Channel> {
+        self.state
+            .borrow()
+            .station
+            .expect("The harness station is created when rendering")
+    }
+
+    /// Mount a new component subscribed to `channel`.
+    pub fn probe(&mut self, channel: Channel) -> Probe {
+        let index = {
+            let mut state = self.state.borrow_mut();
+            state.probes.push(ProbeState {
+                channel,
+                renders: 0,
+                mounted: true,
+            });
+            state.probes.len() - 1
+        };
+        self.dom.mark_dirty(ScopeId::APP);
+        self.render();
+        Probe(index)
+    }
+
+    /// Unmount `probe`, dropping its subscription.
+    pub fn unmount(&mut self, probe: Probe) {
+        self.state.borrow_mut().probes[probe.0].mounted = false;
+        self.dom.mark_dirty(ScopeId::APP);
+        self.render();
+    }
+
+    /// How many times `probe` rendered, including when it was mounted.
+    pub fn renders(&self, probe: Probe) -> usize {
+        self.state.borrow().probes[probe.0].renders
+    }
+
+    /// How many times `probe` rendered again after it was mounted.
+    pub fn reruns(&self, probe: Probe) -> usize {
+        self.renders(probe).saturating_sub(1)
+    }
+
+    /// How many times `channel` was notified.
+    pub fn notified(&self, channel: &Channel) -> usize {
+        self.notified_channels()
+            .iter()
+            .filter(|notified| *notified == channel)
+            .count()
+    }
+
+    /// All the notified channels, in order.
+    pub fn notified_channels(&self) -> Vec<Channel> {
+        self.station()
+            .notified
+            .peek_unchecked()
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Forget the notified channels and the renders of all the probes.
+    pub fn reset_counts(&mut self) {
+        if let Some(notified) = self.station().notified.write_unchecked().as_mut() {
+            notified.clear();
+        }
+        for probe in self.state.borrow_mut().probes.iter_mut() {
+            probe.renders = 0;
+        }
+    }
+
+    /// Get the [Radio] for `channel`, to be used inside [`RadioHarness::run()`].
+    pub fn radio(&self, channel: Channel) -> Radio<Value, Channel> {
+        let station = self.station();
+        let mut state = self.state.borrow_mut();
+        *state.radios.entry(channel.clone()).or_insert_with(|| {
+            self.dom.in_scope(ScopeId::APP, || {
+                Radio::new(Signal::new(RadioAntenna::new(channel, station)))
+            })
+        })
+    }
+
+    /// Run `cb` inside the [VirtualDom] and rerender the notified probes.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let mut radio = harness.radio(DataChannel::ListCreation);
+    ///
+    /// harness.run(|| radio.write().lists.push(Vec::default()));
+    /// ```
+    pub fn run<T>(&mut self, cb: impl FnOnce() -> T) -> T {
+        let result = self.dom.in_scope(ScopeId::APP, cb);
+        self.render();
+        result
+    }
+
+    /// Modify the state notifying `channel`.
+    pub fn write(&mut self, channel: Channel, cb: impl FnOnce(&mut Value)) {
+        let mut radio = self.radio(channel);
+        self.run(|| cb(&mut radio.write()));
+    }
+
+    /// Apply `action` with a [Radio] of `channel`, returning the [ChannelSelection] of the reducer.
+    pub fn apply<Action>(&mut self, channel: Channel, action: Action) -> ChannelSelection<Channel>
+    where
+        Value: DataReducer<Channel = Channel, Action = Action>,
+        Action: 'static,
+    {
+        let mut radio = self.radio(channel);
+        self.run(|| radio.apply(action))
+    }
+
+    /// Restore the initial state of `log` and apply its actions one by one with a [Radio] of `channel`,
+    /// so the notifications and reruns they cause can be checked.
+    ///
+    /// Example:
+    ///
+    /// ```rs
+    /// let log = ActionLog::from_json(&std::fs::read_to_string("bug.json")?)?;
+    ///
+    /// harness.replay::<DataAction>(DataChannel::ListCreation, &log)?;
+    /// ```
+    #[cfg(feature = "recording")]
+    pub fn replay<Action>(
+        &mut self,
+        channel: Channel,
+        log: &crate::hooks::ActionLog,
+    ) -> Result<(), serde_json::Error>
+    where
+        Value: DataReducer<Channel = Channel, Action = Action> + serde::de::DeserializeOwned,
+        Action: serde::de::DeserializeOwned + 'static,
+    {
+        let (state, actions) = log.decode::<Value, Action>()?;
+        let mut radio = self.radio(channel.clone());
+        self.run(|| **radio.write_silently() = state);
+        for action in actions {
+            self.apply(channel.clone(), action);
+        }
+        Ok(())
+    }
+
+    /// Wait for the spawned tasks, e.g. of async reducers, to make progress and rerender the notified probes.
+    pub async fn wait_for_work(&mut self) {
+        self.dom.wait_for_work().await;
+        self.render();
+    }
+
+    fn render(&mut self) {
+        self.dom.render_immediate(&mut NoOpMutations);
+    }
+}
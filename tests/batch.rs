@@ -0,0 +1,115 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default, Clone)]
+struct Data {
+    a: usize,
+    b: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    A,
+    B,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[test]
+fn batch_notifies_each_channel_once() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let a = harness.probe(DataChannel::A);
+    let mut radio = harness.radio(DataChannel::A);
+
+    harness.run(|| {
+        radio.batch(|radio| {
+            radio.write().a += 1;
+            radio.write_channel(DataChannel::B).b += 1;
+            radio.write().a += 1;
+        })
+    });
+
+    assert_eq!(
+        harness.notified_channels(),
+        [DataChannel::A, DataChannel::B]
+    );
+    assert_eq!(harness.reruns(a), 1);
+    assert_eq!(harness.station().peek().a, 2);
+}
+
+#[test]
+fn nested_batches_are_merged() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::A);
+
+    harness.run(|| {
+        radio.batch(|radio| {
+            radio.write().a += 1;
+            radio.batch(|radio| radio.write().a += 1);
+            radio.write().a += 1;
+        })
+    });
+
+    assert_eq!(harness.notified_channels(), [DataChannel::A]);
+}
+
+#[test]
+fn failed_transaction_is_rolled_back() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let a = harness.probe(DataChannel::A);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let result = harness.run(|| {
+        radio.transaction(|radio| {
+            radio.write().a += 1;
+            radio.write_channel(DataChannel::B).b += 1;
+            Err::<(), _>("invalid")
+        })
+    });
+
+    assert_eq!(result, Err("invalid"));
+    assert_eq!(harness.station().peek().a, 0);
+    assert_eq!(harness.station().peek().b, 0);
+    assert!(harness.notified_channels().is_empty());
+    assert_eq!(harness.reruns(a), 0);
+}
+
+#[test]
+fn successful_transaction_is_kept() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let result = harness.run(|| {
+        radio.transaction(|radio| {
+            radio.write().a += 1;
+            Ok::<_, ()>(())
+        })
+    });
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(harness.station().peek().a, 1);
+    assert_eq!(harness.notified_channels(), [DataChannel::A]);
+}
+
+#[test]
+fn panicking_batch_still_ends() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        harness.run(|| {
+            radio.batch(|radio| {
+                radio.write().a += 1;
+                panic!("Failed in the middle of a batch");
+            })
+        })
+    }));
+    assert!(result.is_err());
+
+    harness.write(DataChannel::B, |data| data.b += 1);
+
+    assert_eq!(
+        harness.notified_channels(),
+        [DataChannel::A, DataChannel::B]
+    );
+}
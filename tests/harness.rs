@@ -0,0 +1,105 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default, Clone)]
+struct Data {
+    lists: Vec<Vec<String>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    ListCreation,
+    SpecificListItemUpdate(usize),
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+enum DataAction {
+    AddList,
+    AddItem(usize),
+}
+
+impl DataReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    fn reduce(&mut self, action: DataAction) -> ChannelSelection<DataChannel> {
+        match action {
+            DataAction::AddList => {
+                self.lists.push(Vec::default());
+                ChannelSelection::Current
+            }
+            DataAction::AddItem(list) => {
+                self.lists[list].push("Hello".to_string());
+                ChannelSelection::Select(DataChannel::SpecificListItemUpdate(list))
+            }
+        }
+    }
+}
+
+#[test]
+fn apply_notifies_the_selected_channel() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let creation = harness.probe(DataChannel::ListCreation);
+    let item = harness.probe(DataChannel::SpecificListItemUpdate(0));
+
+    harness.apply(DataChannel::ListCreation, DataAction::AddList);
+
+    assert_eq!(harness.notified_channels(), [DataChannel::ListCreation]);
+    assert_eq!(harness.reruns(creation), 1);
+    assert_eq!(harness.reruns(item), 0);
+
+    let channel_selection = harness.apply(DataChannel::ListCreation, DataAction::AddItem(0));
+
+    assert!(matches!(
+        channel_selection,
+        ChannelSelection::Select(DataChannel::SpecificListItemUpdate(0))
+    ));
+    assert_eq!(harness.notified(&DataChannel::SpecificListItemUpdate(0)), 1);
+    assert_eq!(harness.reruns(creation), 1);
+    assert_eq!(harness.reruns(item), 1);
+}
+
+#[test]
+fn write_only_reruns_subscribers_of_the_channel() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(|| Data {
+        lists: vec![Vec::default(); 2],
+    });
+    let first = harness.probe(DataChannel::SpecificListItemUpdate(0));
+    let second = harness.probe(DataChannel::SpecificListItemUpdate(1));
+
+    harness.write(DataChannel::SpecificListItemUpdate(1), |data| {
+        data.lists[1].push("Hello".to_string())
+    });
+
+    assert_eq!(harness.reruns(first), 0);
+    assert_eq!(harness.reruns(second), 1);
+    assert_eq!(harness.station().peek().lists[1].len(), 1);
+}
+
+#[test]
+fn reset_counts() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let probe = harness.probe(DataChannel::ListCreation);
+
+    harness.apply(DataChannel::ListCreation, DataAction::AddList);
+    harness.reset_counts();
+
+    assert!(harness.notified_channels().is_empty());
+    assert_eq!(harness.renders(probe), 0);
+}
+
+#[test]
+fn harness_is_not_an_observer() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    harness.probe(DataChannel::ListCreation);
+
+    assert_eq!(harness.station().inspect().observers, 0);
+}
+
+#[test]
+fn radios_are_reused() {
+    let harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+
+    assert!(harness.radio(DataChannel::ListCreation) == harness.radio(DataChannel::ListCreation));
+}
@@ -0,0 +1,80 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default)]
+struct Data {
+    items: Vec<usize>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Root,
+    Items,
+    Item(usize),
+}
+
+impl HierarchicalChannel<Data> for DataChannel {
+    fn parent(&self) -> Option<Self> {
+        match self {
+            Self::Root => None,
+            Self::Items => Some(Self::Root),
+            Self::Item(_) => Some(Self::Items),
+        }
+    }
+}
+
+fn harness() -> RadioHarness<Data, DataChannel> {
+    let harness = RadioHarness::<Data, DataChannel>::new(|| Data { items: vec![0; 6] });
+    harness.station().notify_descendants(true);
+    harness
+}
+
+#[test]
+fn siblings_are_not_notified() {
+    let mut harness = harness();
+    let root = harness.probe(DataChannel::Root);
+    let items = harness.probe(DataChannel::Items);
+    let item = harness.probe(DataChannel::Item(3));
+    let sibling = harness.probe(DataChannel::Item(5));
+
+    harness.write(DataChannel::Item(3), |data| data.items[3] += 1);
+
+    assert_eq!(harness.reruns(root), 1);
+    assert_eq!(harness.reruns(items), 1);
+    assert_eq!(harness.reruns(item), 1);
+    assert_eq!(harness.reruns(sibling), 0);
+}
+
+#[test]
+fn children_are_notified_by_their_parent() {
+    let mut harness = harness();
+    let root = harness.probe(DataChannel::Root);
+    let first = harness.probe(DataChannel::Item(3));
+    let second = harness.probe(DataChannel::Item(5));
+
+    harness.write(DataChannel::Items, |data| data.items.clear());
+
+    assert_eq!(harness.reruns(root), 1);
+    assert_eq!(harness.reruns(first), 1);
+    assert_eq!(harness.reruns(second), 1);
+}
+
+#[test]
+fn batched_writes_notify_the_descendants_of_each_write() {
+    let mut harness = harness();
+    let first = harness.probe(DataChannel::Item(3));
+    let second = harness.probe(DataChannel::Item(5));
+    let mut item = harness.radio(DataChannel::Item(3));
+    let mut items = harness.radio(DataChannel::Items);
+    let station = harness.station();
+
+    harness.run(|| {
+        station.batch(|| {
+            item.write().items[3] += 1;
+            items.write().items.push(0);
+        })
+    });
+
+    assert_eq!(harness.reruns(first), 1);
+    assert_eq!(harness.reruns(second), 1);
+}
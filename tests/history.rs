@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default, Clone)]
+struct Data {
+    count: i32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+struct Add(i32);
+
+impl DataAsyncReducer for Data {
+    type Channel = DataChannel;
+    type Action = Add;
+
+    async fn async_reduce(
+        radio: &mut Radio<Data, DataChannel>,
+        Add(amount): Add,
+    ) -> ChannelSelection<DataChannel> {
+        radio.write_silently().count += amount;
+        ChannelSelection::Current
+    }
+}
+
+fn count(harness: &RadioHarness<Data, DataChannel>) -> i32 {
+    harness.station().peek().count
+}
+
+#[test]
+fn undo_and_redo_writes() {
+    let mut harness = RadioHarness::<Data, DataChannel>::with_history(Data::default, 10);
+    let probe = harness.probe(DataChannel::Count);
+    harness.write(DataChannel::Count, |data| data.count = 1);
+    harness.write(DataChannel::Count, |data| data.count = 2);
+    let station = harness.station();
+
+    assert!(harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 1);
+    assert!(harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 0);
+    assert!(!harness.run(|| station.undo()));
+    assert_eq!(harness.reruns(probe), 4);
+
+    assert!(harness.run(|| station.redo()));
+    assert_eq!(count(&harness), 1);
+    assert!(station.can_redo());
+
+    // New writes discard what could be redone
+    harness.write(DataChannel::Count, |data| data.count = 5);
+    assert!(!station.can_redo());
+    assert!(harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 1);
+}
+
+#[test]
+fn only_the_last_writes_are_kept() {
+    let mut harness = RadioHarness::<Data, DataChannel>::with_history(Data::default, 2);
+    for count in 1..=4 {
+        harness.write(DataChannel::Count, |data| data.count = count);
+    }
+    let station = harness.station();
+
+    assert!(harness.run(|| station.undo()));
+    assert!(harness.run(|| station.undo()));
+    assert!(!harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 2);
+}
+
+#[test]
+fn batches_are_undone_at_once() {
+    let mut harness = RadioHarness::<Data, DataChannel>::with_history(Data::default, 10);
+    let mut radio = harness.radio(DataChannel::Count);
+    let station = harness.station();
+
+    harness.run(|| {
+        radio.batch(|radio| {
+            radio.write().count += 1;
+            radio.write().count += 1;
+        })
+    });
+    assert_eq!(count(&harness), 2);
+
+    assert!(harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 0);
+    assert!(!station.can_undo());
+}
+
+#[test]
+fn silent_writes_are_not_recorded() {
+    let mut harness = RadioHarness::<Data, DataChannel>::with_history(Data::default, 10);
+    let mut radio = harness.radio(DataChannel::Count);
+
+    harness.run(|| radio.write_silently().count = 1);
+
+    assert!(!harness.station().can_undo());
+}
+
+#[tokio::test]
+async fn async_actions_are_undone() {
+    let mut harness = RadioHarness::<Data, DataChannel>::with_history(Data::default, 10);
+    let mut radio = harness.radio(DataChannel::Count);
+    let station = harness.station();
+
+    harness.run(|| radio.async_apply(Add(3)));
+    let _ = tokio::time::timeout(Duration::from_millis(20), async {
+        loop {
+            harness.wait_for_work().await;
+        }
+    })
+    .await;
+    assert_eq!(count(&harness), 3);
+
+    assert!(harness.run(|| station.undo()));
+    assert_eq!(count(&harness), 0);
+    assert!(!station.can_undo());
+}
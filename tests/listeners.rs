@@ -0,0 +1,82 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default)]
+struct Data {
+    rows: Vec<usize>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Rows,
+    Row(usize),
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[test]
+fn unmounted_channels_are_cleaned_up() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    harness.probe(DataChannel::Rows);
+    let row = harness.probe(DataChannel::Row(1));
+    assert_eq!(
+        harness
+            .station()
+            .inspect()
+            .channel(&DataChannel::Row(1))
+            .map(|snapshot| snapshot.subscribers),
+        Some(1)
+    );
+
+    harness.unmount(row);
+    harness.write(DataChannel::Rows, |data| data.rows.push(1));
+
+    let snapshot = harness.station().inspect();
+    assert!(snapshot.channel(&DataChannel::Row(1)).is_none());
+    assert_eq!(
+        snapshot
+            .channel(&DataChannel::Rows)
+            .map(|snapshot| snapshot.subscribers),
+        Some(1)
+    );
+}
+
+#[test]
+fn channels_subscribed_again_are_kept() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let row = harness.probe(DataChannel::Row(1));
+
+    harness.unmount(row);
+    let row = harness.probe(DataChannel::Row(1));
+    harness.write(DataChannel::Rows, |data| data.rows.push(1));
+    harness.write(DataChannel::Row(1), |data| data.rows[0] += 1);
+
+    assert_eq!(harness.reruns(row), 1);
+}
+
+#[test]
+fn counters_are_dropped_with_their_channel() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    harness.station().enable_metrics();
+    harness.probe(DataChannel::Rows);
+    let row = harness.probe(DataChannel::Row(1));
+    harness.write(DataChannel::Row(1), |data| data.rows.push(1));
+    harness.write(DataChannel::Row(2), |data| data.rows.push(2));
+
+    let snapshot = harness.station().inspect();
+    assert_eq!(
+        snapshot
+            .channel(&DataChannel::Row(1))
+            .map(|snapshot| snapshot.notifications),
+        Some(1)
+    );
+    assert!(snapshot.channel(&DataChannel::Row(2)).is_none());
+
+    harness.unmount(row);
+    harness.write(DataChannel::Rows, |data| data.rows.clear());
+    assert!(harness
+        .station()
+        .metrics()
+        .channel(&DataChannel::Row(1))
+        .is_none());
+}
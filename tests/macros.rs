@@ -0,0 +1,40 @@
+#![cfg(feature = "macros")]
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default, RadioChannels)]
+struct Data {
+    lists: Vec<String>,
+    item_count: usize,
+}
+
+#[test]
+fn fields_are_read_and_written() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::All);
+
+    harness.run(|| *radio.write_item_count() += 2);
+
+    assert_eq!(harness.run(|| *radio.read_item_count()), 2);
+    assert!(harness.run(|| radio.read_lists().is_empty()));
+}
+
+#[test]
+fn writing_a_field_notifies_its_channel_and_all() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let all = harness.probe(DataChannel::All);
+    let lists = harness.probe(DataChannel::Lists);
+    let item_count = harness.probe(DataChannel::ItemCount);
+    let mut radio = harness.radio(DataChannel::All);
+
+    harness.run(|| *radio.write_item_count() += 1);
+
+    assert_eq!(
+        harness.notified_channels(),
+        [DataChannel::ItemCount, DataChannel::All]
+    );
+    assert_eq!(harness.reruns(all), 1);
+    assert_eq!(harness.reruns(item_count), 1);
+    assert_eq!(harness.reruns(lists), 0);
+}
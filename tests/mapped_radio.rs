@@ -0,0 +1,62 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default)]
+struct Data {
+    lists: Vec<Vec<String>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    ListCreation,
+    SpecificListItemUpdate(usize),
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+fn list(radio: Radio<Data, DataChannel>, list_n: usize) -> MappedRadio<Vec<String>, DataChannel> {
+    radio.map(
+        list_n,
+        move |data| &data.lists[list_n],
+        move |data| &mut data.lists[list_n],
+    )
+}
+
+fn harness() -> RadioHarness<Data, DataChannel> {
+    RadioHarness::new(|| Data {
+        lists: vec![Vec::default(), Vec::default()],
+    })
+}
+
+#[test]
+fn mapped_radios_read_and_write_their_part() {
+    let mut harness = harness();
+    let item = harness.probe(DataChannel::SpecificListItemUpdate(1));
+    let creation = harness.probe(DataChannel::ListCreation);
+    let radio = harness.radio(DataChannel::SpecificListItemUpdate(1));
+    let mut mapped = list(radio, 1);
+
+    harness.run(|| mapped.write().push("Hello".to_string()));
+
+    assert_eq!(harness.run(|| mapped.peek().clone()), ["Hello"]);
+    assert_eq!(harness.station().peek().lists[0].len(), 0);
+    assert_eq!(harness.reruns(item), 1);
+    assert_eq!(harness.reruns(creation), 0);
+
+    harness.run(|| mapped.write_channel(DataChannel::ListCreation).clear());
+
+    assert_eq!(harness.station().peek().lists[1].len(), 0);
+    assert_eq!(harness.reruns(creation), 1);
+}
+
+#[test]
+fn mapped_radios_of_the_same_part_are_equal() {
+    let harness = harness();
+    let radio = harness.radio(DataChannel::SpecificListItemUpdate(0));
+    let other_radio = harness.radio(DataChannel::ListCreation);
+
+    assert!(list(radio, 0) == list(radio, 0));
+    assert!(list(radio, 0) != list(radio, 1));
+    assert!(list(radio, 0) != list(other_radio, 0));
+    assert!(list(radio, 0) != radio.map(0, |data| &data.lists[0], |data| &mut data.lists[0]));
+}
@@ -0,0 +1,57 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default)]
+struct Data {
+    count: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[test]
+fn metrics_are_disabled_by_default() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    harness.probe(DataChannel::Count);
+    harness.write(DataChannel::Count, |data| data.count += 1);
+
+    let metrics = harness.station().metrics();
+    let counters = metrics.channel(&DataChannel::Count).unwrap();
+    assert_eq!(counters.subscribers, 1);
+    assert_eq!(counters.writes, 0);
+    assert_eq!(counters.notifications, 0);
+
+    // Notifications are always counted for inspecting the station
+    let snapshot = harness.station().inspect();
+    assert_eq!(
+        snapshot
+            .channel(&DataChannel::Count)
+            .map(|snapshot| snapshot.notifications),
+        Some(1)
+    );
+}
+
+#[test]
+fn enabled_metrics_count_writes_and_reruns() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    harness.station().enable_metrics();
+    harness.probe(DataChannel::Count);
+    harness.probe(DataChannel::Count);
+    harness.write(DataChannel::Count, |data| data.count += 1);
+    harness.write(DataChannel::Count, |data| data.count += 1);
+
+    let metrics = harness.station().metrics();
+    let counters = metrics.channel(&DataChannel::Count).unwrap();
+    assert_eq!(counters.writes, 2);
+    assert_eq!(counters.notifications, 2);
+    assert_eq!(counters.dirty_marks, 4);
+    assert_eq!(counters.subscribers_per_notification(), 2.0);
+
+    harness.station().reset_metrics();
+    let metrics = harness.station().metrics();
+    assert_eq!(metrics.channel(&DataChannel::Count).unwrap().writes, 0);
+}
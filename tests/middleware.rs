@@ -0,0 +1,168 @@
+use std::{cell::RefCell, ops::ControlFlow, rc::Rc};
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default, Clone, Debug, PartialEq)]
+struct Data {
+    count: i32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum DataAction {
+    Add(i32),
+    Reset,
+}
+
+impl DataReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    fn reduce(&mut self, action: DataAction) -> ChannelSelection<DataChannel> {
+        match action {
+            DataAction::Add(amount) => self.count += amount,
+            DataAction::Reset => self.count = 0,
+        }
+        ChannelSelection::Current
+    }
+}
+
+type Log = Rc<RefCell<Vec<(DataAction, i32, i32)>>>;
+
+/// Logs the applied actions along with the previous and next counts.
+struct Logger(Log);
+
+impl RadioMiddleware<Data, DataChannel, DataAction> for Logger {
+    fn after(
+        &mut self,
+        action: &DataAction,
+        previous: &Data,
+        next: &Data,
+        _channel_selection: &ChannelSelection<DataChannel>,
+    ) {
+        self.0
+            .borrow_mut()
+            .push((action.clone(), previous.count, next.count));
+    }
+}
+
+/// Vetoes negative additions and caps the rest to 10.
+struct Validator;
+
+impl RadioMiddleware<Data, DataChannel, DataAction> for Validator {
+    fn before(&mut self, action: &mut DataAction, _state: &Data) -> ControlFlow<()> {
+        match action {
+            DataAction::Add(amount) if *amount < 0 => return ControlFlow::Break(()),
+            DataAction::Add(amount) => *amount = (*amount).min(10),
+            DataAction::Reset => {}
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[test]
+fn vetoed_actions_are_not_reduced() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let log = Log::default();
+    harness.station().add_middleware(Validator);
+    harness.station().add_middleware(Logger(log.clone()));
+    let probe = harness.probe(DataChannel::Count);
+
+    harness.apply(DataChannel::Count, DataAction::Add(-1));
+
+    assert_eq!(harness.station().peek().count, 0);
+    assert_eq!(harness.reruns(probe), 0);
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn after_gets_the_rewritten_action_and_both_states() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let log = Log::default();
+    harness.station().add_middleware(Validator);
+    harness.station().add_middleware(Logger(log.clone()));
+
+    harness.apply(DataChannel::Count, DataAction::Add(3));
+    harness.apply(DataChannel::Count, DataAction::Add(50));
+
+    assert_eq!(harness.station().peek().count, 13);
+    assert_eq!(
+        *log.borrow(),
+        [(DataAction::Add(3), 0, 3), (DataAction::Add(10), 3, 13)]
+    );
+}
+
+/// Resets the count once it goes over 5.
+struct FollowUp(Radio<Data, DataChannel>);
+
+impl RadioMiddleware<Data, DataChannel, DataAction> for FollowUp {
+    fn after(
+        &mut self,
+        _action: &DataAction,
+        _previous: &Data,
+        next: &Data,
+        _channel_selection: &ChannelSelection<DataChannel>,
+    ) {
+        if next.count > 5 {
+            self.0.apply(DataAction::Reset);
+        }
+    }
+}
+
+#[test]
+fn middlewares_can_apply_actions() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let log = Log::default();
+    let radio = harness.radio(DataChannel::Count);
+    harness.station().add_middleware(FollowUp(radio));
+    harness.station().add_middleware(Logger(log.clone()));
+
+    harness.apply(DataChannel::Count, DataAction::Add(6));
+
+    assert_eq!(harness.station().peek().count, 0);
+    assert_eq!(
+        *log.borrow(),
+        [(DataAction::Reset, 6, 0), (DataAction::Add(6), 0, 6)]
+    );
+}
+
+/// Registers a [Logger] when it first runs.
+struct Registrar {
+    station: RadioStation<Data, DataChannel>,
+    log: Option<Log>,
+}
+
+impl RadioMiddleware<Data, DataChannel, DataAction> for Registrar {
+    fn before(&mut self, _action: &mut DataAction, _state: &Data) -> ControlFlow<()> {
+        if let Some(log) = self.log.take() {
+            self.station.add_middleware(Logger(log));
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[test]
+fn middlewares_can_register_middlewares() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let log = Log::default();
+    let station = harness.station();
+    station.add_middleware(Registrar {
+        station,
+        log: Some(log.clone()),
+    });
+
+    harness.apply(DataChannel::Count, DataAction::Add(1));
+    harness.apply(DataChannel::Count, DataAction::Add(1));
+
+    assert_eq!(
+        *log.borrow(),
+        [(DataAction::Add(1), 0, 1), (DataAction::Add(1), 1, 2)]
+    );
+}
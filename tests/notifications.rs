@@ -0,0 +1,78 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::dioxus_core::{NoOpMutations, ScopeId, VirtualDom};
+use dioxus::prelude::*;
+use dioxus_radio::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    count: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+    Other,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+type Log = Rc<RefCell<Vec<DataChannel>>>;
+
+thread_local! {
+    static LOG: Log = Log::default();
+}
+
+#[derive(Clone, Copy)]
+struct Handles {
+    count: Radio<Data, DataChannel>,
+    other: Radio<Data, DataChannel>,
+    listening: Signal<bool>,
+}
+
+fn app() -> Element {
+    use_init_radio_station::<Data, DataChannel>(Data::default);
+    let handles = Handles {
+        count: use_radio(DataChannel::Count),
+        other: use_radio(DataChannel::Other),
+        listening: use_signal(|| true),
+    };
+    use_context_provider(|| handles);
+    rsx!(if (handles.listening)() {
+        Listener {}
+    })
+}
+
+#[component]
+fn Listener() -> Element {
+    use_radio_notifications::<Data, DataChannel>(|channel| {
+        LOG.with(|log| log.borrow_mut().push(*channel))
+    });
+    rsx!()
+}
+
+fn handles(dom: &VirtualDom) -> Handles {
+    dom.in_scope(ScopeId::APP, consume_context::<Handles>)
+}
+
+fn logged() -> Vec<DataChannel> {
+    LOG.with(|log| log.borrow().clone())
+}
+
+#[test]
+fn callbacks_get_every_notified_channel_while_mounted() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut handles = handles(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        handles.count.write().count += 1;
+        handles.other.write().count += 1;
+    });
+    assert_eq!(logged(), [DataChannel::Count, DataChannel::Other]);
+
+    dom.in_scope(ScopeId::APP, || handles.listening.set(false));
+    dom.render_immediate(&mut NoOpMutations);
+    dom.in_scope(ScopeId::APP, || handles.count.write().count += 1);
+    assert_eq!(logged().len(), 2);
+}
@@ -0,0 +1,75 @@
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+
+#[derive(Default)]
+struct Data {
+    a: i32,
+    b: i32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    A,
+    B,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[test]
+fn optimistic_update_notifies_right_away() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let a = harness.probe(DataChannel::A);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let update = harness.run(|| radio.optimistic(|data| data.a += 10, |data| data.a -= 10));
+    assert_eq!(harness.station().peek().a, 10);
+    assert_eq!(harness.reruns(a), 1);
+
+    harness.run(|| update.commit());
+    assert_eq!(harness.station().peek().a, 10);
+    assert_eq!(harness.reruns(a), 1);
+}
+
+#[test]
+fn revert_keeps_the_writes_made_in_between() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let update = harness.run(|| radio.optimistic(|data| data.a += 10, |data| data.a -= 10));
+    harness.write(DataChannel::B, |data| data.b = 5);
+    harness.run(|| update.revert());
+
+    assert_eq!(harness.station().peek().a, 0);
+    assert_eq!(harness.station().peek().b, 5);
+}
+
+#[test]
+fn revert_keeps_the_layers_applied_after() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let a = harness.probe(DataChannel::A);
+    let mut radio = harness.radio(DataChannel::A);
+
+    let first = harness.run(|| radio.optimistic(|data| data.a += 10, |data| data.a -= 10));
+    let second = harness.run(|| radio.optimistic(|data| data.a += 1, |data| data.a -= 1));
+    harness.run(|| first.revert());
+
+    assert_eq!(harness.station().peek().a, 1);
+    assert_eq!(harness.reruns(a), 3);
+
+    harness.run(|| second.commit());
+    assert_eq!(harness.station().peek().a, 1);
+}
+
+#[test]
+fn dropped_update_is_reverted() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::A);
+
+    harness.run(|| drop(radio.optimistic(|data| data.a += 10, |data| data.a -= 10)));
+
+    assert_eq!(harness.station().peek().a, 0);
+    assert_eq!(
+        harness.notified_channels(),
+        [DataChannel::A, DataChannel::A]
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "persistence")]
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use dioxus::dioxus_core::{ScopeId, VirtualDom};
+use dioxus::prelude::*;
+use dioxus_radio::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+struct Data {
+    count: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+/// [RadioStorage] kept in memory, shared with the test.
+#[derive(Clone, Default)]
+struct MemoryStorage(Rc<RefCell<Option<String>>>);
+
+impl RadioStorage for MemoryStorage {
+    fn load(&self) -> Option<String> {
+        self.0.borrow().clone()
+    }
+
+    fn save(&self, state: &str) {
+        *self.0.borrow_mut() = Some(state.to_string());
+    }
+}
+
+fn app(storage: MemoryStorage) -> Element {
+    station(storage, Duration::ZERO)
+}
+
+fn debounced_app(storage: MemoryStorage) -> Element {
+    station(storage, Duration::from_secs(60))
+}
+
+fn station(storage: MemoryStorage, debounce: Duration) -> Element {
+    use_init_radio_station::<Data, DataChannel>(Data::default);
+    use_radio_persistence::<Data, DataChannel>(|| {
+        RadioPersistence::new(storage, [DataChannel::Count]).debounce(debounce)
+    });
+    let radio = use_radio::<Data, DataChannel>(DataChannel::Count);
+    use_context_provider(|| radio);
+    rsx!()
+}
+
+async fn write(dom: &mut VirtualDom) {
+    dom.in_scope(ScopeId::APP, || {
+        consume_context::<Radio<Data, DataChannel>>().write().count += 1
+    });
+    let _ = tokio::time::timeout(Duration::from_millis(20), async {
+        loop {
+            dom.wait_for_work().await;
+        }
+    })
+    .await;
+}
+
+fn count(dom: &VirtualDom) -> usize {
+    dom.in_scope(ScopeId::APP, || {
+        consume_context::<Radio<Data, DataChannel>>().read().count
+    })
+}
+
+#[tokio::test]
+async fn writes_are_saved_and_loaded() {
+    let storage = MemoryStorage::default();
+    let mut dom = VirtualDom::new_with_props(app, storage.clone());
+    dom.rebuild_in_place();
+    write(&mut dom).await;
+    assert!(storage.0.borrow().is_some());
+
+    let mut dom = VirtualDom::new_with_props(app, storage);
+    dom.rebuild_in_place();
+    assert_eq!(count(&dom), 1);
+}
+
+#[tokio::test]
+async fn unreadable_states_are_not_overwritten() {
+    let storage = MemoryStorage(Rc::new(RefCell::new(Some("{".to_string()))));
+    let mut dom = VirtualDom::new_with_props(app, storage.clone());
+    dom.rebuild_in_place();
+    write(&mut dom).await;
+
+    assert_eq!(count(&dom), 1);
+    assert_eq!(storage.0.borrow().as_deref(), Some("{"));
+}
+
+#[tokio::test]
+async fn pending_saves_are_flushed_on_drop() {
+    let storage = MemoryStorage::default();
+    let mut dom = VirtualDom::new_with_props(debounced_app, storage.clone());
+    dom.rebuild_in_place();
+    write(&mut dom).await;
+    assert!(storage.0.borrow().is_none());
+
+    drop(dom);
+    let mut dom = VirtualDom::new_with_props(app, storage);
+    dom.rebuild_in_place();
+    assert_eq!(count(&dom), 1);
+}
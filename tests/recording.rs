@@ -0,0 +1,139 @@
+#![cfg(feature = "recording")]
+
+use std::{ops::ControlFlow, time::Duration};
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct Data {
+    count: i32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+enum DataChannel {
+    Count,
+    Other,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum DataAction {
+    Add(i32),
+    Touch,
+}
+
+impl DataReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    fn reduce(&mut self, action: DataAction) -> ChannelSelection<DataChannel> {
+        match action {
+            DataAction::Add(amount) => {
+                self.count += amount;
+                ChannelSelection::Current
+            }
+            DataAction::Touch => ChannelSelection::Select(DataChannel::Other),
+        }
+    }
+}
+
+impl DataAsyncReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    async fn async_reduce(
+        radio: &mut Radio<Data, DataChannel>,
+        action: DataAction,
+    ) -> ChannelSelection<DataChannel> {
+        radio.write_silently().reduce(action)
+    }
+}
+
+/// Vetoes negative additions.
+struct Veto;
+
+impl RadioMiddleware<Data, DataChannel, DataAction> for Veto {
+    fn before(&mut self, action: &mut DataAction, _state: &Data) -> ControlFlow<()> {
+        match action {
+            DataAction::Add(amount) if *amount < 0 => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+}
+
+#[test]
+fn vetoed_actions_are_not_recorded() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let recorder = harness.station().record_actions::<DataAction>();
+    harness.station().add_middleware::<DataAction>(Veto);
+
+    harness.apply(DataChannel::Count, DataAction::Add(1));
+    harness.apply(DataChannel::Count, DataAction::Add(-1));
+    harness.apply(DataChannel::Count, DataAction::Touch);
+
+    let log = recorder.log();
+    let (_, actions) = log.decode::<Data, DataAction>().unwrap();
+    assert_eq!(actions, vec![DataAction::Add(1), DataAction::Touch]);
+
+    let selections = log
+        .actions
+        .iter()
+        .map(|recorded| recorded.channel_selection.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        selections,
+        vec![
+            serde_json::to_value(ChannelSelection::<DataChannel>::Current).ok(),
+            serde_json::to_value(ChannelSelection::Select(DataChannel::Other)).ok(),
+        ]
+    );
+    assert_eq!(log.replay::<Data, DataAction>().unwrap().count, 1);
+}
+
+#[test]
+fn failed_transactions_are_not_recorded() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let recorder = harness.station().record_actions::<DataAction>();
+    let mut radio = harness.radio(DataChannel::Count);
+
+    let result = harness.run(|| {
+        radio.transaction(|radio| {
+            radio.apply(DataAction::Add(5));
+            Err::<(), ()>(())
+        })
+    });
+    assert!(result.is_err());
+    harness
+        .run(|| {
+            radio.transaction(|radio| {
+                radio.apply(DataAction::Add(2));
+                Ok::<(), ()>(())
+            })
+        })
+        .unwrap();
+
+    let log = recorder.log();
+    assert_eq!(harness.station().peek().count, 2);
+    assert_eq!(log.replay::<Data, DataAction>().unwrap().count, 2);
+}
+
+#[tokio::test]
+async fn async_actions_are_not_recorded() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let recorder = harness.station().record_actions::<DataAction>();
+    let mut radio = harness.radio(DataChannel::Count);
+
+    harness.run(|| radio.async_apply(DataAction::Add(1)));
+    let _ = tokio::time::timeout(Duration::from_millis(20), async {
+        loop {
+            harness.wait_for_work().await;
+        }
+    })
+    .await;
+
+    assert_eq!(harness.station().peek().count, 1);
+    assert!(recorder.is_empty());
+}
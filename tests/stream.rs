@@ -0,0 +1,64 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+use futures_util::Stream;
+
+#[derive(Default)]
+struct Data {
+    count: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Count,
+    Other,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+fn poll(stream: &mut RadioStream) -> Poll<Option<()>> {
+    Pin::new(stream).poll_next(&mut Context::from_waker(Waker::noop()))
+}
+
+#[test]
+fn stream_yields_merged_notifications() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut stream = harness.station().subscribe_stream(DataChannel::Count);
+    assert_eq!(poll(&mut stream), Poll::Pending);
+
+    harness.write(DataChannel::Other, |data| data.count += 1);
+    assert_eq!(poll(&mut stream), Poll::Pending);
+
+    harness.write(DataChannel::Count, |data| data.count += 1);
+    harness.write(DataChannel::Count, |data| data.count += 1);
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(())));
+    assert_eq!(poll(&mut stream), Poll::Pending);
+}
+
+#[test]
+fn dropped_streams_are_cleaned_up() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let stream = harness.station().subscribe_stream(DataChannel::Count);
+    drop(stream);
+    harness.write(DataChannel::Other, |data| data.count += 1);
+
+    assert!(harness
+        .station()
+        .inspect()
+        .channel(&DataChannel::Count)
+        .is_none());
+}
+
+#[test]
+fn streams_outliving_the_station_can_be_dropped() {
+    let harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut stream = harness.station().subscribe_stream(DataChannel::Count);
+    drop(harness);
+
+    assert_eq!(poll(&mut stream), Poll::Pending);
+    drop(stream);
+}
@@ -0,0 +1,159 @@
+use std::{rc::Rc, time::Duration};
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+use tokio::sync::Notify;
+
+thread_local! {
+    /// Released by the tests to let the running actions finish.
+    static GATE: Rc<Notify> = Rc::new(Notify::new());
+}
+
+#[derive(Default, Clone)]
+struct Data {
+    done: Vec<usize>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord)]
+enum DataChannel {
+    Done,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+enum DataAction {
+    Parallel(usize),
+    Queue(usize),
+    TakeLatest(usize),
+    DropWhileRunning(usize),
+}
+
+impl DataAsyncReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    async fn async_reduce(
+        radio: &mut Radio<Data, DataChannel>,
+        action: DataAction,
+    ) -> ChannelSelection<DataChannel> {
+        let gate = GATE.with(|gate| gate.clone());
+        gate.notified().await;
+        let (DataAction::Parallel(id)
+        | DataAction::Queue(id)
+        | DataAction::TakeLatest(id)
+        | DataAction::DropWhileRunning(id)) = action;
+        radio.write_silently().done.push(id);
+        ChannelSelection::Current
+    }
+
+    fn concurrency(action: &DataAction) -> ConcurrencyPolicy {
+        match action {
+            DataAction::Parallel(_) => ConcurrencyPolicy::Parallel,
+            DataAction::Queue(_) => ConcurrencyPolicy::Queue,
+            DataAction::TakeLatest(_) => ConcurrencyPolicy::TakeLatest,
+            DataAction::DropWhileRunning(_) => ConcurrencyPolicy::DropWhileRunning,
+        }
+    }
+}
+
+/// Let the spawned tasks run until they are all waiting.
+async fn settle(harness: &mut RadioHarness<Data, DataChannel>) {
+    let _ = tokio::time::timeout(Duration::from_millis(20), async {
+        loop {
+            harness.wait_for_work().await;
+        }
+    })
+    .await;
+}
+
+/// Let the running actions finish.
+async fn release(harness: &mut RadioHarness<Data, DataChannel>) {
+    GATE.with(|gate| gate.notify_waiters());
+    settle(harness).await;
+}
+
+fn done(harness: &RadioHarness<Data, DataChannel>) -> Vec<usize> {
+    harness.station().peek().done.clone()
+}
+
+#[tokio::test]
+async fn parallel_actions_run_together() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::Done);
+
+    let first = harness.run(|| radio.async_apply(DataAction::Parallel(1)));
+    let second = harness.run(|| radio.async_apply(DataAction::Parallel(2)));
+    settle(&mut harness).await;
+    assert_eq!(first.status(), TaskStatus::Running);
+    assert_eq!(second.status(), TaskStatus::Running);
+
+    release(&mut harness).await;
+    assert_eq!(first.status(), TaskStatus::Completed);
+    assert_eq!(second.status(), TaskStatus::Completed);
+    assert_eq!(done(&harness), [1, 2]);
+}
+
+#[tokio::test]
+async fn queued_actions_run_in_order() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::Done);
+
+    let first = harness.run(|| radio.async_apply(DataAction::Queue(1)));
+    let second = harness.run(|| radio.async_apply(DataAction::Queue(2)));
+    settle(&mut harness).await;
+    assert_eq!(first.status(), TaskStatus::Running);
+    assert_eq!(second.status(), TaskStatus::Queued);
+
+    release(&mut harness).await;
+    assert_eq!(first.status(), TaskStatus::Completed);
+    assert_eq!(second.status(), TaskStatus::Running);
+    assert_eq!(done(&harness), [1]);
+
+    release(&mut harness).await;
+    assert_eq!(second.status(), TaskStatus::Completed);
+    assert_eq!(done(&harness), [1, 2]);
+}
+
+#[tokio::test]
+async fn latest_action_supersedes_the_running_one() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::Done);
+
+    let first = harness.run(|| radio.async_apply(DataAction::TakeLatest(1)));
+    settle(&mut harness).await;
+    let second = harness.run(|| radio.async_apply(DataAction::TakeLatest(2)));
+    assert!(first.is_superseded());
+
+    release(&mut harness).await;
+    assert_eq!(second.status(), TaskStatus::Completed);
+    assert_eq!(done(&harness), [2]);
+}
+
+#[tokio::test]
+async fn actions_are_dropped_while_running() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::Done);
+
+    let first = harness.run(|| radio.async_apply(DataAction::DropWhileRunning(1)));
+    let second = harness.run(|| radio.async_apply(DataAction::DropWhileRunning(2)));
+    assert_eq!(second.status(), TaskStatus::Dropped);
+
+    settle(&mut harness).await;
+    release(&mut harness).await;
+    assert_eq!(first.status(), TaskStatus::Completed);
+    assert_eq!(done(&harness), [1]);
+}
+
+#[tokio::test]
+async fn cancelled_actions_never_finish() {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let mut radio = harness.radio(DataChannel::Done);
+
+    let task = harness.run(|| radio.async_apply(DataAction::Parallel(1)));
+    settle(&mut harness).await;
+    harness.run(|| task.cancel());
+    assert_eq!(task.status(), TaskStatus::Cancelled);
+
+    release(&mut harness).await;
+    assert!(done(&harness).is_empty());
+}
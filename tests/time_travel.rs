@@ -0,0 +1,133 @@
+#![cfg(feature = "recording")]
+
+use dioxus_radio::prelude::*;
+use dioxus_radio::testing::RadioHarness;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct Data {
+    count: i32,
+    other: i32,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+enum DataChannel {
+    Count,
+    Other,
+}
+
+impl RadioChannel<Data> for DataChannel {}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum DataAction {
+    Add(i32),
+    AddOther(i32),
+}
+
+impl DataReducer for Data {
+    type Channel = DataChannel;
+    type Action = DataAction;
+
+    fn reduce(&mut self, action: DataAction) -> ChannelSelection<DataChannel> {
+        match action {
+            DataAction::Add(amount) => {
+                self.count += amount;
+                ChannelSelection::Select(DataChannel::Count)
+            }
+            DataAction::AddOther(amount) => {
+                self.other += amount;
+                ChannelSelection::Select(DataChannel::Other)
+            }
+        }
+    }
+}
+
+fn count(harness: &mut RadioHarness<Data, DataChannel>) -> i32 {
+    let station = harness.station();
+    harness.run(|| station.peek().count)
+}
+
+fn recorded_harness() -> RadioHarness<Data, DataChannel> {
+    let mut harness = RadioHarness::<Data, DataChannel>::new(Data::default);
+    let station = harness.station();
+    harness.run(|| station.record_actions::<DataAction>());
+    harness.apply(DataChannel::Count, DataAction::Add(1));
+    harness.apply(DataChannel::Count, DataAction::Add(2));
+    harness.apply(DataChannel::Other, DataAction::AddOther(3));
+    harness
+}
+
+#[test]
+fn travel_to_shows_recorded_states() {
+    let mut harness = recorded_harness();
+    let station = harness.station();
+
+    harness.run(|| station.travel_to::<DataAction>(1)).unwrap();
+    assert_eq!(count(&mut harness), 1);
+    assert_eq!(harness.run(|| station.travel_position()), Some(1));
+
+    harness.run(|| station.travel_to::<DataAction>(0)).unwrap();
+    assert_eq!(count(&mut harness), 0);
+
+    assert!(matches!(
+        harness.run(|| station.travel_to::<DataAction>(4)),
+        Err(TimeTravelError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn travelling_notifies_the_channels_of_the_actions_in_between() {
+    let mut harness = recorded_harness();
+    let station = harness.station();
+    let count = harness.probe(DataChannel::Count);
+    let other = harness.probe(DataChannel::Other);
+
+    // Leaving the live state notifies every subscriber
+    harness.run(|| station.travel_to::<DataAction>(3)).unwrap();
+    assert_eq!(harness.reruns(count), 1);
+    assert_eq!(harness.reruns(other), 1);
+
+    harness.reset_counts();
+    harness.run(|| station.travel_to::<DataAction>(2)).unwrap();
+    assert_eq!(harness.renders(count), 0);
+    assert_eq!(harness.renders(other), 1);
+
+    harness.reset_counts();
+    harness.run(|| station.travel_to::<DataAction>(0)).unwrap();
+    assert_eq!(harness.renders(count), 1);
+    assert_eq!(harness.renders(other), 0);
+}
+
+#[test]
+fn resume_brings_back_the_live_state() {
+    let mut harness = recorded_harness();
+    let station = harness.station();
+    let probe = harness.probe(DataChannel::Other);
+
+    harness.run(|| station.travel_to::<DataAction>(0)).unwrap();
+    harness.reset_counts();
+    harness.run(|| station.resume());
+
+    assert_eq!(count(&mut harness), 3);
+    assert_eq!(harness.run(|| station.travel_position()), None);
+    assert_eq!(harness.renders(probe), 1);
+
+    harness.apply(DataChannel::Count, DataAction::Add(1));
+    assert_eq!(harness.run(|| station.recorded_actions()), 4);
+}
+
+#[test]
+fn writes_while_travelling_are_discarded() {
+    let mut harness = recorded_harness();
+    let station = harness.station();
+
+    harness.run(|| station.travel_to::<DataAction>(1)).unwrap();
+    harness.apply(DataChannel::Count, DataAction::Add(10));
+    harness.write(DataChannel::Count, |data| data.count += 10);
+    assert_eq!(count(&mut harness), 21);
+    assert_eq!(harness.run(|| station.recorded_actions()), 3);
+
+    harness.run(|| station.resume());
+    assert_eq!(count(&mut harness), 3);
+    assert_eq!(harness.run(|| station.recorded_actions()), 3);
+}